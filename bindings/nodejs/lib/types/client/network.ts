@@ -21,6 +21,10 @@ export interface IAuth {
     jwt?: string;
     /** Basic authentication parameters. */
     basicAuthNamePwd?: [string, string];
+    /** Custom headers added to every request. */
+    headers?: { [name: string]: string };
+    /** PEM encoded root CA certificate which replaces the system's root certificates for the node. */
+    rootCertificate?: string;
 }
 
 /**
@@ -108,6 +108,16 @@ export interface SyncOptions {
     syncOnlyMostBasicOutputs?: boolean;
     /** Sync native token foundries, so their metadata can be returned in the balance. Default: false. */
     syncNativeTokenFoundries?: boolean;
+    /**
+     * Only outputs with an amount within this inclusive range are considered unspent. Outputs outside of it are excluded
+     * from the balance and won't be used as inputs, even though the funds are still there. Default: no range.
+     */
+    amountRange?: { start: number; end: number };
+    /**
+     * Consolidates the outputs of the account at the end of the sync if there are enough of them. This submits a
+     * transaction and is disabled by default.
+     */
+    autoConsolidate?: AutoConsolidateOptions;
 }
 
 /** Specifies what outputs should be synced for the ed25519 addresses from the account. */
@@ -142,6 +152,12 @@ export interface NftSyncOptions {
     nftOutputs?: boolean;
 }
 
+/** Options for the consolidation done at the end of a sync. */
+export interface AutoConsolidateOptions {
+    /** Consolidates if the number of outputs that can be consolidated is >= the output threshold. */
+    outputThreshold?: number;
+}
+
 /** The account object. */
 export interface AccountMeta {
     /** The account index. */
@@ -160,6 +176,8 @@ export interface AccountMeta {
     outputs: { [outputId: string]: OutputData };
     /** All IDs of unspent outputs that are currently used as inputs for transactions. */
     lockedOutputs: Set<string>;
+    /** All IDs of unspent outputs that are frozen, so they aren't used as inputs for transactions. */
+    frozenOutputs: Set<string>;
     /** All unspent outputs of the account. */
     unspentOutputs: { [outputId: string]: OutputData };
     /** All transactions of the account. */
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 import { Bech32Address, NftId, OutputId, TokenId } from '../block';
-import { NumericString } from '../utils';
+import { HexEncodedString, NumericString } from '../utils';
 
 /** An Address of the Account */
 export interface AccountAddress {
@@ -34,6 +34,17 @@ export interface SendParams {
      * expiration is needed but not provided, it will default to one day.
      */
     expiration?: number;
+    /**
+     * Unix timestamp in seconds until which the output can't be unlocked by the receiver. It needs to be in the
+     * future and, if the output also gets an expiration, before the expiration.
+     */
+    timelock?: number;
+    /** Bech32 encoded sender feature, it needs to be an address of the account. */
+    sender?: Bech32Address;
+    /** Hex encoded metadata feature. */
+    metadata?: HexEncodedString;
+    /** Hex encoded tag feature. */
+    tag?: HexEncodedString;
 }
 
 /** Address with unspent outputs */
@@ -72,6 +83,16 @@ export interface SendNftParams {
     address: Bech32Address;
     /** The ID of the NFT to send. */
     nftId: NftId;
+    /**
+     * Bech32 encoded return address, it needs to be an address of the account. Only used with an expiration, defaults
+     * to the first address of the account.
+     */
+    returnAddress?: Bech32Address;
+    /**
+     * Expiration in seconds, after which the NFT will be available for the sender again, if not claimed by the
+     * receiver already. The receiver has to return the storage deposit of the NFT output when claiming it.
+     */
+    expiration?: number;
 }
 
 /** Options for address generation, useful with a Ledger Nano SecretManager */
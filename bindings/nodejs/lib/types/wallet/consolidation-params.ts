@@ -9,4 +9,16 @@ export interface ConsolidationParams {
     outputThreshold?: number;
     /** Address to which the consolidated output should be sent. */
     targetAddress?: string;
+    /** How outputs holding native tokens are consolidated, defaults to `Merge`. */
+    nativeTokenStrategy?: NativeTokenConsolidationStrategy;
+}
+
+/** How outputs holding native tokens are consolidated */
+export enum NativeTokenConsolidationStrategy {
+    /** Merges all native tokens into a single consolidated output. */
+    Merge = 'Merge',
+    /** Creates a consolidated output per set of native token IDs. */
+    GroupByTokenSet = 'GroupByTokenSet',
+    /** Doesn't consolidate outputs holding native tokens. */
+    Skip = 'Skip',
 }
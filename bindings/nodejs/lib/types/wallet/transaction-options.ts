@@ -1,7 +1,7 @@
 // Copyright 2021-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-import { AliasId, Bech32Address } from '../block';
+import { AliasId, Bech32Address, Feature } from '../block';
 import { TaggedDataPayload } from '../block/payload/tagged';
 import { Burn } from '../client';
 import { HexEncodedString } from '../utils';
@@ -27,6 +27,14 @@ export interface TransactionOptions {
     note?: string;
     /** Whether to allow sending a micro amount. */
     allowMicroAmount?: boolean;
+    /** Whether to allow burning aliases which still control foundries or NFTs. */
+    allowOrphaning?: boolean;
+    /** Whether input selection can add inputs besides the custom or mandatory inputs, true by default. */
+    allowAdditionalInputSelection?: boolean;
+    /** Whether to check that the secret manager can sign the transaction, so a Ledger Nano without blind signing doesn't get asked to sign a transaction it would reject. */
+    checkSignerCapabilities?: boolean;
+    /** Features to add to the remainder output, only metadata and tag features are supported. */
+    remainderFeatures?: Feature[];
 }
 
 /** The possible remainder value strategies. */
@@ -7,7 +7,7 @@ from .external import *
 from .utils import Utils
 from .wallet.wallet import Wallet, Account
 from .wallet.common import WalletError
-from .wallet.sync_options import AccountSyncOptions, NftSyncOptions, AliasSyncOptions, SyncOptions
+from .wallet.sync_options import AccountSyncOptions, NftSyncOptions, AliasSyncOptions, AutoConsolidateOptions, SyncOptions
 from .secret_manager.secret_manager import *
 from .prefix_hex import *
 from .types.address import *
@@ -28,7 +28,7 @@ class Node():
     """
 
     def __init__(self, url=None, jwt=None, username=None,
-                 password=None, disabled=None):
+                 password=None, disabled=None, headers=None, root_certificate=None):
         """Initialize a Node.
 
         Args:
@@ -37,22 +37,31 @@ class Node():
             username: A username for basic authentication.
             password: A password for basic authentication.
             disabled: Whether the node should be used for API requests or not.
+            headers: Custom headers added to every request.
+            root_certificate: PEM encoded root CA certificate which replaces the system's root certificates
+                for the node.
         """
         self.url = url
         self.jwt = jwt
         self.username = username
         self.password = password
         self.disabled = disabled
+        self.headers = headers
+        self.root_certificate = root_certificate
 
     def as_dict(self):
         """Converts this object to a dict.
         """
         config = {k: v for k, v in self.__dict__.items() if v is not None}
 
-        if 'jwt' in config or 'username' in config or 'password' in config:
+        if any(key in config for key in ['jwt', 'username', 'password', 'headers', 'root_certificate']):
             config['auth'] = {}
             if 'jwt' in config:
                 config['auth']['jwt'] = config.pop('jwt')
+            if 'headers' in config:
+                config['auth']['headers'] = config.pop('headers')
+            if 'root_certificate' in config:
+                config['auth']['rootCertificate'] = config.pop('root_certificate')
             if 'username' in config or 'password' in config:
                 basic_auth = config['auth']['basicAuthNamePwd'] = []
                 if 'username' in config:
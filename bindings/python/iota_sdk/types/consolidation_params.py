@@ -2,9 +2,23 @@
 # SPDX-License-Identifier: Apache-2.0
 
 from dataclasses import dataclass
+from enum import Enum
 from typing import Optional
 
 
+class NativeTokenConsolidationStrategy(str, Enum):
+    """How outputs holding native tokens are consolidated.
+
+    Attributes:
+        Merge: Merges all native tokens into a single consolidated output.
+        GroupByTokenSet: Creates a consolidated output per set of native token IDs.
+        Skip: Doesn't consolidate outputs holding native tokens.
+    """
+    Merge = 'Merge'
+    GroupByTokenSet = 'GroupByTokenSet'
+    Skip = 'Skip'
+
+
 @dataclass
 class ConsolidationParams:
     """Parameters for consolidation.
@@ -16,8 +30,11 @@ class ConsolidationParams:
             Consolidates if the output number is >= the output_threshold.
         targetAddress (Optional[str]):
             Address to which the consolidated output should be sent.
+        nativeTokenStrategy (Optional[NativeTokenConsolidationStrategy]):
+            How outputs holding native tokens are consolidated, defaults to `Merge`.
     """
 
     force: bool
     outputThreshold: Optional[int] = None
     targetAddress: Optional[str] = None
+    nativeTokenStrategy: Optional[NativeTokenConsolidationStrategy] = None
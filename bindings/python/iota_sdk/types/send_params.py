@@ -19,11 +19,20 @@ class SendParams():
         expiration: Expiration in seconds, after which the output will be available for the sender again, if not spent by the
         receiver already. The expiration will only be used if one is necessary given the provided amount. If an
         expiration is needed but not provided, it will default to one day.
+        timelock: Unix timestamp in seconds until which the output can't be unlocked by the receiver. It needs to be in
+        the future and, if the output also gets an expiration, before the expiration.
+        sender: Bech32 encoded sender feature, it needs to be an address of the account.
+        metadata: Hex encoded metadata feature.
+        tag: Hex encoded tag feature.
     """
     address: str
     amount: str
     returnAddress: Optional[str] = None
     expiration: Optional[int] = None
+    timelock: Optional[int] = None
+    sender: Optional[str] = None
+    metadata: Optional[HexStr] = None
+    tag: Optional[HexStr] = None
 
     def as_dict(self):
         """Converts this object to a dict.
@@ -63,9 +72,15 @@ class SendNftParams():
     Attributes:
         address: The address to send the NFT to.
         nftId: The ID of the NFT to send.
+        returnAddress: The address to return the NFT to if not claimed, it needs to be an address of the account. Only
+        used with an expiration, defaults to the first address of the account.
+        expiration: Expiration in seconds, after which the NFT will be available for the sender again, if not claimed
+        by the receiver already. The receiver has to return the storage deposit of the NFT output when claiming it.
     """
     address: str
     nftId: HexStr
+    returnAddress: Optional[str] = None
+    expiration: Optional[int] = None
 
 
 @dataclass
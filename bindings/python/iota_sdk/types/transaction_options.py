@@ -4,6 +4,7 @@
 from enum import Enum
 from typing import Optional, List, Union
 from iota_sdk.types.burn import Burn
+from iota_sdk.types.feature import Feature
 from iota_sdk.types.output_id import OutputId
 from iota_sdk.types.payload import TaggedDataPayload
 
@@ -61,6 +62,11 @@ class TransactionOptions():
         burn: Specifies what needs to be burned during input selection.
         note: A string attached to the transaction.
         allow_micro_amount: Whether to allow sending a micro amount.
+        allow_orphaning: Whether to allow burning aliases which still control foundries or NFTs.
+        allow_additional_input_selection: Whether input selection can add inputs besides the custom or mandatory inputs, true by default.
+        check_signer_capabilities: Whether to check that the secret manager can sign the transaction, so a Ledger Nano
+            without blind signing doesn't get asked to sign a transaction it would reject.
+        remainder_features: Features to add to the remainder output, only metadata and tag features are supported.
     """
 
     def __init__(self, remainder_value_strategy: Optional[Union[RemainderValueStrategy, RemainderValueStrategyCustomAddress]] = None,
@@ -69,7 +75,11 @@ class TransactionOptions():
                  mandatory_inputs: Optional[List[OutputId]] = None,
                  burn: Optional[Burn] = None,
                  note: Optional[str] = None,
-                 allow_micro_amount: Optional[bool] = None):
+                 allow_micro_amount: Optional[bool] = None,
+                 allow_orphaning: Optional[bool] = None,
+                 allow_additional_input_selection: Optional[bool] = None,
+                 check_signer_capabilities: Optional[bool] = None,
+                 remainder_features: Optional[List[Feature]] = None):
         """Initialize transaction options.
         """
         self.remainder_value_strategy = remainder_value_strategy
@@ -79,6 +89,10 @@ class TransactionOptions():
         self.burn = burn
         self.note = note
         self.allow_micro_amount = allow_micro_amount
+        self.allow_orphaning = allow_orphaning
+        self.allow_additional_input_selection = allow_additional_input_selection
+        self.check_signer_capabilities = check_signer_capabilities
+        self.remainder_features = remainder_features
 
     def as_dict(self):
         """Converts this object to a dict.
@@ -88,4 +102,8 @@ class TransactionOptions():
         if 'remainder_value_strategy' in config:
             config['remainder_value_strategy'] = config['remainder_value_strategy'].as_dict()
 
+        if 'remainder_features' in config:
+            config['remainder_features'] = [
+                feature.as_dict() for feature in config['remainder_features']]
+
         return config
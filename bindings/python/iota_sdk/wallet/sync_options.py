@@ -1,7 +1,7 @@
 # Copyright 2023 IOTA Stiftung
 # SPDX-License-Identifier: Apache-2.0
 
-from typing import List, Optional
+from typing import List, Optional, Tuple
 
 
 class AccountSyncOptions():
@@ -67,6 +67,20 @@ class NftSyncOptions():
         self.aliasOutputs = alias_outputs
 
 
+class AutoConsolidateOptions():
+    """Options for the consolidation done at the end of a sync.
+
+    Attributes:
+        output_threshold: Consolidates if the number of outputs that can be consolidated is >= the output threshold.
+    """
+
+    def __init__(self,
+                 output_threshold: Optional[int] = None):
+        """Initialize `Self`.
+        """
+        self.outputThreshold = output_threshold
+
+
 class SyncOptions():
     """The synchronization options.
 
@@ -103,6 +117,13 @@ class SyncOptions():
         This will overwrite the `account`, `alias` and `nft` options.
     sync_native_token_foundries :
         Sync native token foundries, so their metadata can be returned in the balance.
+    amount_range :
+        Only outputs with an amount within this inclusive (start, end) range are considered unspent.
+        Outputs outside of it are excluded from the balance and won't be used as inputs,
+        even though the funds are still there.
+    auto_consolidate :
+        Consolidates the outputs of the account at the end of the sync if there are enough of them.
+        This submits a transaction and is disabled by default.
     """
 
     def __init__(self,
@@ -116,7 +137,9 @@ class SyncOptions():
                  alias: Optional[AliasSyncOptions] = None,
                  nft: Optional[NftSyncOptions] = None,
                  sync_only_most_basic_outputs: Optional[bool] = None,
-                 sync_native_token_foundries: Optional[bool] = None):
+                 sync_native_token_foundries: Optional[bool] = None,
+                 amount_range: Optional[Tuple[int, int]] = None,
+                 auto_consolidate: Optional[AutoConsolidateOptions] = None):
         """Initialize `Self`.
         """
         self.addresses = addresses
@@ -130,6 +153,9 @@ class SyncOptions():
         self.nft = nft
         self.syncOnlyMostBasicOutputs = sync_only_most_basic_outputs
         self.syncNativeTokenFoundries = sync_native_token_foundries
+        self.amountRange = None if amount_range is None else {
+            'start': amount_range[0], 'end': amount_range[1]}
+        self.autoConsolidate = auto_consolidate
 
     def as_dict(self):
         """Converts this object to a dict.
@@ -138,6 +138,84 @@ impl SecretManager {
                 .collect(),
         )
     }
+
+    /// Get the EVM address derived with the same BIP44 path as the given bech32 address.
+    ///
+    /// The bech32 address is derived from the Ed25519 key and the EVM address from the Secp256k1 key of the path
+    /// `m/44'/{coin_type}'/{account_index}'/{internal}'/{address_index}'`, so both addresses are controlled by the same
+    /// seed and path, but neither can be computed from the other one without the secret manager. The address index is
+    /// searched in the provided range.
+    pub async fn evm_address_from_bech32_address(
+        &self,
+        bech32_address: &Bech32Address,
+        coin_type: u32,
+        account_index: u32,
+        range: Range<u32>,
+    ) -> Result<String> {
+        let (address_index, internal) = search_address(
+            self,
+            *bech32_address.hrp(),
+            coin_type,
+            account_index,
+            range,
+            bech32_address.inner(),
+        )
+        .await?;
+
+        let mut options = GetAddressesOptions::default()
+            .with_coin_type(coin_type)
+            .with_account_index(account_index)
+            .with_range(address_index..address_index + 1);
+        if internal {
+            options = options.internal();
+        }
+
+        // Safe to unwrap because exactly one address is generated
+        Ok(self.generate_evm_addresses(options).await?.pop().unwrap())
+    }
+
+    /// Get the bech32 address derived with the same BIP44 path as the given hex encoded EVM address.
+    ///
+    /// This is the inverse of [`SecretManager::evm_address_from_bech32_address()`], the address index is searched in
+    /// the provided range.
+    pub async fn bech32_address_from_evm_address(
+        &self,
+        evm_address: &str,
+        bech32_hrp: Hrp,
+        coin_type: u32,
+        account_index: u32,
+        range: Range<u32>,
+    ) -> Result<Bech32Address> {
+        let options = GetAddressesOptions::default()
+            .with_coin_type(coin_type)
+            .with_account_index(account_index)
+            .with_range(range.clone())
+            .with_bech32_hrp(bech32_hrp);
+
+        let public = self.generate_evm_addresses(options.clone()).await?;
+        let internal = self.generate_evm_addresses(options.clone().internal()).await?;
+
+        for index in 0..public.len() {
+            let address_range = range.start + index as u32..range.start + index as u32 + 1;
+            if public[index].eq_ignore_ascii_case(evm_address) {
+                return Ok(self
+                    .generate_ed25519_addresses(options.with_range(address_range))
+                    .await?
+                    .remove(0));
+            }
+            if internal[index].eq_ignore_ascii_case(evm_address) {
+                return Ok(self
+                    .generate_ed25519_addresses(options.internal().with_range(address_range))
+                    .await?
+                    .remove(0));
+            }
+        }
+
+        Err(crate::client::Error::InputAddressNotFound {
+            address: evm_address.to_string(),
+            range: format!("{range:?}"),
+        })
+    }
 }
 
 /// Function to find the index and public (false) or internal (true) type of an Bech32 encoded address
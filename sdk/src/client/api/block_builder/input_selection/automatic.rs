@@ -20,7 +20,6 @@ use crate::{
         Error, Result,
     },
     types::block::{address::Bech32Address, output::OutputWithMetadata, protocol::ProtocolParameters, ConvertTo},
-    utils::unix_timestamp_now,
 };
 
 impl<'a> ClientBlockBuilder<'a> {
@@ -51,7 +50,7 @@ impl<'a> ClientBlockBuilder<'a> {
                     QueryParameter::HasExpiration(true),
                     QueryParameter::HasStorageDepositReturn(false),
                     // Ignore outputs that aren't expired yet
-                    QueryParameter::ExpiresBefore(unix_timestamp_now().as_secs() as u32),
+                    QueryParameter::ExpiresBefore(self.client.unix_timestamp_now().as_secs() as u32),
                 ])
                 .await?
                 .items,
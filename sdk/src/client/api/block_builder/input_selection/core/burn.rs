@@ -7,10 +7,14 @@ use std::collections::{HashMap, HashSet};
 use primitive_types::U256;
 use serde::{Deserialize, Serialize};
 
-use crate::types::block::output::{AliasId, FoundryId, NativeToken, NftId, TokenId};
+use super::Error;
+use crate::types::block::output::{AliasId, ChainId, FoundryId, NativeToken, NftId, TokenId};
 
 /// A type to specify what needs to be burned during input selection.
 /// Nothing will be burned that has not been explicitly set with this struct.
+///
+/// A single item can be converted into a [`Burn`] from an [`AliasId`], an [`NftId`], a [`FoundryId`], a
+/// [`NativeToken`] or a `(TokenId, U256)` tuple.
 #[derive(Debug, Default, Clone, Eq, PartialEq)]
 pub struct Burn {
     /// Aliases to burn.
@@ -29,6 +33,11 @@ impl Burn {
         Self::default()
     }
 
+    /// Creates a new [`BurnBuilder`], which validates the [`Burn`] when finished.
+    pub fn builder() -> BurnBuilder {
+        BurnBuilder::default()
+    }
+
     /// Adds an alias to [`Burn`].
     pub fn add_alias(mut self, alias_id: AliasId) -> Self {
         self.aliases.insert(alias_id);
@@ -99,6 +108,110 @@ impl Burn {
     pub fn native_tokens(&self) -> &BTreeMap<TokenId, U256> {
         &self.native_tokens
     }
+
+    /// Returns the IDs of the aliases, foundries and NFTs to [`Burn`].
+    pub fn chain_ids(&self) -> impl Iterator<Item = ChainId> + '_ {
+        self.aliases
+            .iter()
+            .copied()
+            .map(ChainId::from)
+            .chain(self.foundries.iter().copied().map(ChainId::from))
+            .chain(self.nfts.iter().copied().map(ChainId::from))
+    }
+
+    /// Returns the number of chains and native tokens to [`Burn`].
+    pub fn len(&self) -> usize {
+        self.aliases.len() + self.nfts.len() + self.foundries.len() + self.native_tokens.len()
+    }
+
+    /// Returns whether there is nothing to [`Burn`].
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Merges another [`Burn`] into this one.
+    /// Like with the [`BurnBuilder`], chains or native tokens which are in both are rejected.
+    pub fn merge(mut self, other: Self) -> Result<Self, Error> {
+        for chain_id in other.chain_ids() {
+            if !self.insert_chain(chain_id) {
+                return Err(Error::DuplicateBurnChainId(chain_id));
+            }
+        }
+
+        for (token_id, amount) in other.native_tokens {
+            if self.native_tokens.insert(token_id, amount).is_some() {
+                return Err(Error::DuplicateBurnNativeToken(token_id));
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Inserts a chain, returns whether it was new.
+    fn insert_chain(&mut self, chain_id: ChainId) -> bool {
+        match chain_id {
+            ChainId::Alias(alias_id) => self.aliases.insert(alias_id),
+            ChainId::Foundry(foundry_id) => self.foundries.insert(foundry_id),
+            ChainId::Nft(nft_id) => self.nfts.insert(nft_id),
+        }
+    }
+}
+
+/// A builder to incrementally assemble a [`Burn`].
+/// Unlike the methods of [`Burn`], duplicate IDs and zero native token amounts are rejected when finishing.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+#[must_use]
+pub struct BurnBuilder {
+    chains: Vec<ChainId>,
+    native_tokens: Vec<(TokenId, U256)>,
+}
+
+impl BurnBuilder {
+    /// Adds an alias to [`BurnBuilder`].
+    pub fn add_alias(mut self, alias_id: AliasId) -> Self {
+        self.chains.push(ChainId::Alias(alias_id));
+        self
+    }
+
+    /// Adds an NFT to [`BurnBuilder`].
+    pub fn add_nft(mut self, nft_id: NftId) -> Self {
+        self.chains.push(ChainId::Nft(nft_id));
+        self
+    }
+
+    /// Adds a foundry to [`BurnBuilder`].
+    pub fn add_foundry(mut self, foundry_id: FoundryId) -> Self {
+        self.chains.push(ChainId::Foundry(foundry_id));
+        self
+    }
+
+    /// Adds an amount of native token to [`BurnBuilder`].
+    pub fn add_native_token(mut self, token_id: TokenId, amount: impl Into<U256>) -> Self {
+        self.native_tokens.push((token_id, amount.into()));
+        self
+    }
+
+    /// Finishes the [`BurnBuilder`] into a [`Burn`].
+    pub fn finish(self) -> Result<Burn, Error> {
+        let mut burn = Burn::new();
+
+        for chain_id in self.chains {
+            if !burn.insert_chain(chain_id) {
+                return Err(Error::DuplicateBurnChainId(chain_id));
+            }
+        }
+
+        for (token_id, amount) in self.native_tokens {
+            if amount.is_zero() {
+                return Err(Error::ZeroNativeTokenBurnAmount(token_id));
+            }
+            if burn.native_tokens.insert(token_id, amount).is_some() {
+                return Err(Error::DuplicateBurnNativeToken(token_id));
+            }
+        }
+
+        Ok(burn)
+    }
 }
 
 impl From<FoundryId> for Burn {
@@ -125,6 +238,12 @@ impl From<NativeToken> for Burn {
     }
 }
 
+impl From<(TokenId, U256)> for Burn {
+    fn from((token_id, amount): (TokenId, U256)) -> Self {
+        Self::new().add_native_token(token_id, amount)
+    }
+}
+
 /// A DTO for [`Burn`].
 #[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
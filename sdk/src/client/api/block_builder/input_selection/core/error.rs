@@ -8,7 +8,10 @@ use std::fmt::Debug;
 use primitive_types::U256;
 
 use super::Requirement;
-use crate::types::block::output::{ChainId, OutputId, TokenId};
+use crate::types::block::{
+    input::INPUT_COUNT_RANGE,
+    output::{ChainId, OutputId, TokenId, OUTPUT_COUNT_RANGE},
+};
 
 /// Errors related to input selection.
 #[derive(Debug, Eq, PartialEq, thiserror::Error)]
@@ -19,6 +22,12 @@ pub enum Error {
     /// Can't burn and transition an output at the same time.
     #[error("can't burn and transition an output at the same time, chain ID: {0}")]
     BurnAndTransition(ChainId),
+    /// A chain to burn was provided more than once.
+    #[error("chain to burn was provided more than once, chain ID: {0}")]
+    DuplicateBurnChainId(ChainId),
+    /// A native token to burn was provided more than once.
+    #[error("native token to burn was provided more than once, token ID: {0}")]
+    DuplicateBurnNativeToken(TokenId),
     /// Insufficient amount provided.
     #[error("insufficient amount: found {found}, required {required}")]
     InsufficientAmount {
@@ -38,10 +47,12 @@ pub enum Error {
         required: U256,
     },
     /// Invalid amount of inputs.
-    #[error("invalid amount of inputs: {0}")]
+    #[error("invalid amount of inputs: {0}, it needs to be in the range {INPUT_COUNT_RANGE:?}")]
     InvalidInputCount(usize),
     /// Invalid amount of outputs.
-    #[error("invalid amount of outputs: {0}")]
+    #[error(
+        "invalid amount of outputs: {0}, it needs to be in the range {OUTPUT_COUNT_RANGE:?}; split them into multiple transactions"
+    )]
     InvalidOutputCount(usize),
     /// No input with matching ed25519 address provided.
     #[error("no input with matching ed25519 address provided")]
@@ -55,7 +66,13 @@ pub enum Error {
     /// Required input is not available.
     #[error("required input {0} is not available")]
     RequiredInputIsNotAvailable(OutputId),
+    /// Unsupported remainder feature, only metadata and tag features can be added to the remainder.
+    #[error("unsupported remainder feature kind {0}")]
+    UnsupportedRemainderFeature(u8),
     /// Unfulfillable requirement.
     #[error("unfulfillable requirement {0:?}")]
     UnfulfillableRequirement(Requirement),
+    /// Native token burn amount is zero.
+    #[error("native token burn amount is zero, token ID: {0}")]
+    ZeroNativeTokenBurnAmount(TokenId),
 }
@@ -14,7 +14,7 @@ use packable::PackableExt;
 pub(crate) use requirement::is_alias_transition;
 
 pub use self::{
-    burn::{Burn, BurnDto},
+    burn::{Burn, BurnBuilder, BurnDto},
     error::Error,
     requirement::Requirement,
 };
@@ -24,8 +24,8 @@ use crate::{
         address::{Address, AliasAddress, NftAddress},
         input::INPUT_COUNT_RANGE,
         output::{
-            AliasOutput, AliasTransition, ChainId, FoundryOutput, NativeTokensBuilder, NftOutput, Output, OutputId,
-            OUTPUT_COUNT_RANGE,
+            AliasOutput, AliasTransition, ChainId, Feature, FoundryOutput, NativeTokensBuilder, NftOutput, Output,
+            OutputId, OUTPUT_COUNT_RANGE,
         },
         protocol::ProtocolParameters,
     },
@@ -42,6 +42,7 @@ pub struct InputSelection {
     addresses: HashSet<Address>,
     burn: Option<Burn>,
     remainder_address: Option<Address>,
+    remainder_features: Vec<Feature>,
     protocol_parameters: ProtocolParameters,
     timestamp: u32,
     requirements: Vec<Requirement>,
@@ -187,6 +188,7 @@ impl InputSelection {
             addresses,
             burn: None,
             remainder_address: None,
+            remainder_features: Vec::new(),
             protocol_parameters,
             timestamp: unix_timestamp_now().as_secs() as u32,
             requirements: Vec::new(),
@@ -218,6 +220,13 @@ impl InputSelection {
         self
     }
 
+    /// Sets the features of the remainder output of an [`InputSelection`], only metadata and tag features are
+    /// supported.
+    pub fn remainder_features(mut self, features: impl IntoIterator<Item = Feature>) -> Self {
+        self.remainder_features = features.into_iter().collect();
+        self
+    }
+
     /// Sets the timestamp of an [`InputSelection`].
     pub fn timestamp(mut self, timestamp: u32) -> Self {
         self.timestamp = timestamp;
@@ -361,13 +370,21 @@ impl InputSelection {
     /// Selects inputs that meet the requirements of the outputs to satisfy the semantic validation of the overall
     /// transaction. Also creates a remainder output and chain transition outputs if required.
     pub fn select(mut self) -> Result<Selected, Error> {
-        if !OUTPUT_COUNT_RANGE.contains(&(self.outputs.len() as u16)) {
+        if !u16::try_from(self.outputs.len()).is_ok_and(|count| OUTPUT_COUNT_RANGE.contains(&count)) {
             // If burn is provided, outputs will be added later
             if !(self.outputs.is_empty() && self.burn.is_some()) {
                 return Err(Error::InvalidOutputCount(self.outputs.len()));
             }
         }
 
+        if let Some(feature) = self
+            .remainder_features
+            .iter()
+            .find(|feature| !feature.is_metadata() && !feature.is_tag())
+        {
+            return Err(Error::UnsupportedRemainderFeature(feature.kind()));
+        }
+
         self.filter_inputs();
 
         if self.available_inputs.is_empty() {
@@ -388,7 +405,7 @@ impl InputSelection {
             }
         }
 
-        if !INPUT_COUNT_RANGE.contains(&(self.selected_inputs.len() as u16)) {
+        if !u16::try_from(self.selected_inputs.len()).is_ok_and(|count| INPUT_COUNT_RANGE.contains(&count)) {
             return Err(Error::InvalidInputCount(self.selected_inputs.len()));
         }
 
@@ -401,7 +418,7 @@ impl InputSelection {
         self.outputs.extend(storage_deposit_returns);
 
         // Check again, because more outputs may have been added.
-        if !OUTPUT_COUNT_RANGE.contains(&(self.outputs.len() as u16)) {
+        if !u16::try_from(self.outputs.len()).is_ok_and(|count| OUTPUT_COUNT_RANGE.contains(&count)) {
             return Err(Error::InvalidOutputCount(self.outputs.len()));
         }
 
@@ -83,7 +83,8 @@ impl InputSelection {
             BasicOutputBuilder::new_with_minimum_storage_deposit(*self.protocol_parameters.rent_structure())
                 .add_unlock_condition(AddressUnlockCondition::new(Address::from(Ed25519Address::from(
                     [0; 32],
-                ))));
+                ))))
+                .with_features(self.remainder_features.clone());
 
         if let Some(native_tokens) = native_tokens_diff {
             remainder_builder = remainder_builder.with_native_tokens(native_tokens);
@@ -147,7 +148,9 @@ impl InputSelection {
         let diff = inputs_sum - outputs_sum;
         let mut remainder_builder = BasicOutputBuilder::new_with_amount(diff);
 
-        remainder_builder = remainder_builder.add_unlock_condition(AddressUnlockCondition::new(remainder_address));
+        remainder_builder = remainder_builder
+            .add_unlock_condition(AddressUnlockCondition::new(remainder_address))
+            .with_features(self.remainder_features.clone());
 
         if let Some(native_tokens) = native_tokens_diff {
             log::debug!("Adding {native_tokens:?} to remainder output for {remainder_address:?}");
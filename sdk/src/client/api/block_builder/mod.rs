@@ -174,7 +174,7 @@ impl<'a> ClientBlockBuilder<'a> {
             .add_unlock_condition(AddressUnlockCondition::new(address))
             .finish_output(self.client.get_token_supply().await?)?;
         self.outputs.push(output);
-        if !OUTPUT_COUNT_RANGE.contains(&(self.outputs.len() as u16)) {
+        if !u16::try_from(self.outputs.len()).is_ok_and(|count| OUTPUT_COUNT_RANGE.contains(&count)) {
             return Err(crate::client::Error::Block(
                 crate::types::block::Error::InvalidOutputCount(TryIntoBoundedU16Error::Truncated(self.outputs.len())),
             ));
@@ -185,7 +185,7 @@ impl<'a> ClientBlockBuilder<'a> {
     /// Set outputs to the builder
     pub fn with_outputs(mut self, outputs: impl IntoIterator<Item = Output>) -> Result<Self> {
         self.outputs.extend(outputs);
-        if !OUTPUT_COUNT_RANGE.contains(&(self.outputs.len() as u16)) {
+        if !u16::try_from(self.outputs.len()).is_ok_and(|count| OUTPUT_COUNT_RANGE.contains(&count)) {
             return Err(crate::client::Error::Block(
                 crate::types::block::Error::InvalidOutputCount(TryIntoBoundedU16Error::Truncated(self.outputs.len())),
             ));
@@ -199,7 +199,7 @@ impl<'a> ClientBlockBuilder<'a> {
             .add_unlock_condition(AddressUnlockCondition::new(address.parse::<Ed25519Address>()?))
             .finish_output(self.client.get_token_supply().await?)?;
         self.outputs.push(output);
-        if !OUTPUT_COUNT_RANGE.contains(&(self.outputs.len() as u16)) {
+        if !u16::try_from(self.outputs.len()).is_ok_and(|count| OUTPUT_COUNT_RANGE.contains(&count)) {
             return Err(crate::client::Error::Block(
                 crate::types::block::Error::InvalidOutputCount(TryIntoBoundedU16Error::Truncated(self.outputs.len())),
             ));
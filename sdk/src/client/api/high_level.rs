@@ -3,6 +3,7 @@
 
 use std::collections::HashSet;
 
+use crypto::keys::bip44::Bip44;
 use futures::{StreamExt, TryStreamExt};
 
 use crate::{
@@ -13,6 +14,7 @@ use crate::{
         },
         error::{Error, Result},
         node_api::indexer::query_parameters::QueryParameter,
+        secret::types::InputSigningData,
         Client,
     },
     types::{
@@ -20,7 +22,7 @@ use crate::{
         block::{
             address::Bech32Address,
             input::{Input, UtxoInput, INPUT_COUNT_MAX},
-            output::OutputWithMetadata,
+            output::{OutputId, OutputWithMetadata},
             parent::Parents,
             payload::{
                 transaction::{TransactionEssence, TransactionId},
@@ -29,7 +31,6 @@ use crate::{
             Block, BlockId,
         },
     },
-    utils::unix_timestamp_now,
 };
 
 impl Client {
@@ -57,6 +58,23 @@ impl Client {
         self.get_outputs(&input_ids).await
     }
 
+    /// Get the [`InputSigningData`] of an output from the node, to use it as input of a manually built transaction.
+    /// The `chain` the address of the output was derived from is required to sign with a mnemonic, seed, Stronghold
+    /// or Ledger Nano secret manager. The output isn't checked to be unspent.
+    pub async fn input_signing_data(
+        &self,
+        output_id: &OutputId,
+        chain: impl Into<Option<Bip44>> + Send,
+    ) -> Result<InputSigningData> {
+        let output_with_metadata = self.get_output(output_id).await?;
+
+        Ok(InputSigningData {
+            output: output_with_metadata.output,
+            output_metadata: output_with_metadata.metadata,
+            chain: chain.into(),
+        })
+    }
+
     /// Get a builder that can be used to construct a block in parts.
     pub fn build_block(&self) -> ClientBlockBuilder<'_> {
         ClientBlockBuilder::new(self)
@@ -287,7 +305,7 @@ impl Client {
     /// Returns the local time checked with the timestamp of the latest milestone, if the difference is larger than 5
     /// minutes an error is returned to prevent locking outputs by accident for a wrong time.
     pub async fn get_time_checked(&self) -> Result<u32> {
-        let current_time = unix_timestamp_now().as_secs() as u32;
+        let current_time = self.unix_timestamp_now().as_secs() as u32;
 
         let network_info = self.get_network_info().await?;
 
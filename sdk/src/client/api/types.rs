@@ -1,6 +1,8 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::{BTreeMap, HashSet};
+
 use crypto::keys::bip44::Bip44;
 use serde::{Deserialize, Serialize};
 
@@ -9,7 +11,8 @@ use crate::{
     types::{
         block::{
             address::{dto::AddressDto, Address},
-            output::{dto::OutputDto, Output},
+            input::Input,
+            output::{dto::OutputDto, AliasTransition, InputsCommitment, Output, OutputId},
             payload::{
                 transaction::{
                     dto::{TransactionEssenceDto, TransactionPayloadDto},
@@ -17,6 +20,8 @@ use crate::{
                 },
                 TransactionPayload,
             },
+            protocol::ProtocolParameters,
+            semantic::{verify_balances, BalanceError},
             Error,
         },
         TryFromDto, ValidationParams,
@@ -35,6 +40,249 @@ pub struct PreparedTransactionData {
     pub remainder: Option<RemainderData>,
 }
 
+impl PreparedTransactionData {
+    /// Checks that the signed transaction data is for this transaction, so it has the same essence and inputs. Signed
+    /// transaction data that was returned by another party, like an offline signer, should be checked before it's
+    /// submitted.
+    pub fn matches_signed(&self, signed: &SignedTransactionData) -> bool {
+        signed.transaction_payload.essence() == &self.essence && signed.inputs_data == self.inputs_data
+    }
+
+    /// Splits the JSON of the prepared transaction data into numbered chunks of at most `max_chunk_bytes` bytes, so it
+    /// can be transferred in small parts, for example as animated QR codes to an offline signer. Each chunk has the
+    /// format `<index>/<count>/<hex encoded bytes>`, with an index starting at 1. Use
+    /// [`PreparedTransactionData::from_chunks()`] to reassemble them. Fails if `max_chunk_bytes` is 0.
+    pub fn to_chunks(&self, max_chunk_bytes: usize) -> crate::client::Result<Vec<String>> {
+        if max_chunk_bytes == 0 {
+            return Err(crate::client::Error::InvalidChunk(
+                "chunks need to hold at least 1 byte".to_string(),
+            ));
+        }
+        let bytes = serde_json::to_vec(&PreparedTransactionDataDto::from(self))?;
+        let chunks = bytes.chunks(max_chunk_bytes);
+        let count = chunks.len();
+
+        Ok(chunks
+            .enumerate()
+            .map(|(index, chunk)| format!("{}/{count}/{}", index + 1, prefix_hex::encode(chunk)))
+            .collect())
+    }
+
+    /// Reassembles prepared transaction data from the chunks created with
+    /// [`PreparedTransactionData::to_chunks()`]. The chunks can be provided in any order and repeatedly, but all of
+    /// them are required.
+    pub fn from_chunks(chunks: impl IntoIterator<Item = impl AsRef<str>>) -> crate::client::Result<Self> {
+        // The count is taken from the chunks, so parts are only stored for chunks that are actually provided
+        let mut parts = BTreeMap::new();
+        let mut expected_count = None;
+
+        for chunk in chunks {
+            let chunk = chunk.as_ref();
+            let invalid_chunk = || crate::client::Error::InvalidChunk(chunk.to_string());
+            let mut split = chunk.splitn(3, '/');
+            let (Some(index), Some(count), Some(data)) = (split.next(), split.next(), split.next()) else {
+                return Err(invalid_chunk());
+            };
+            let index = index.parse::<usize>().map_err(|_| invalid_chunk())?;
+            let count = count.parse::<usize>().map_err(|_| invalid_chunk())?;
+
+            if *expected_count.get_or_insert(count) != count || index == 0 || index > count {
+                return Err(invalid_chunk());
+            }
+            parts.insert(index, prefix_hex::decode::<Vec<u8>>(data)?);
+        }
+
+        let Some(count) = expected_count else {
+            return Err(crate::client::Error::InvalidChunk("no chunks provided".to_string()));
+        };
+        if parts.len() != count {
+            // Safe to unwrap since fewer than `count` parts are stored, the search stops at the first missing index
+            let missing = (1..=count).find(|index| !parts.contains_key(index)).unwrap();
+            return Err(crate::client::Error::InvalidChunk(format!(
+                "chunk {missing} is missing"
+            )));
+        }
+
+        let bytes = parts.into_values().flatten().collect::<Vec<_>>();
+
+        Ok(Self::try_from_dto(
+            serde_json::from_slice::<PreparedTransactionDataDto>(&bytes)?,
+        )?)
+    }
+
+    /// Verifies the prepared transaction before it's signed, so an invalid transaction can be detected before it's
+    /// handed to a signer, for example an offline one. Checks that the inputs data matches the essence, that the
+    /// base coin and native token amounts balance, that all inputs can be unlocked, that storage deposit returns are
+    /// fulfilled and that the remainder is part of the outputs. Time locks and expirations are evaluated at
+    /// `current_time`, in seconds since the unix epoch, for example from
+    /// [`Client::unix_timestamp_now()`](crate::client::Client::unix_timestamp_now).
+    pub fn verify_semantic(
+        &self,
+        protocol_parameters: &ProtocolParameters,
+        current_time: u32,
+    ) -> Result<(), PreparedTransactionError> {
+        let TransactionEssence::Regular(essence) = &self.essence;
+
+        if essence.network_id() != protocol_parameters.network_id() {
+            return Err(PreparedTransactionError::NetworkIdMismatch {
+                expected: protocol_parameters.network_id(),
+                found: essence.network_id(),
+            });
+        }
+
+        // Validation of the inputs data against the essence.
+        if essence.inputs().len() != self.inputs_data.len() {
+            return Err(PreparedTransactionError::InputsCountMismatch {
+                essence: essence.inputs().len(),
+                inputs_data: self.inputs_data.len(),
+            });
+        }
+
+        for (index, (input, input_data)) in essence.inputs().iter().zip(&self.inputs_data).enumerate() {
+            match input {
+                Input::Utxo(input) if input.output_id() == input_data.output_id() => {}
+                _ => return Err(PreparedTransactionError::InputMismatch(index)),
+            }
+        }
+
+        if essence.inputs_commitment() != &InputsCommitment::new(self.inputs_data.iter().map(|i| &i.output)) {
+            return Err(PreparedTransactionError::InputsCommitmentMismatch);
+        }
+
+        let mut unlocked_addresses = HashSet::new();
+        let mut required_addresses = Vec::new();
+
+        // Validation of the addresses required to unlock the inputs.
+        for input in &self.inputs_data {
+            let output_id = input.output_id();
+            let (required_address, unlocked_address) = input
+                .output
+                .required_and_unlocked_address(current_time, output_id, self.alias_transition(input))
+                .map_err(|_| PreparedTransactionError::UnsupportedInput(*output_id))?;
+
+            required_addresses.push((*output_id, required_address));
+            unlocked_addresses.extend(unlocked_address);
+        }
+
+        // Ed25519 addresses are unlocked with a signature, alias and nft addresses need their output as input.
+        for (output_id, required_address) in required_addresses {
+            if !required_address.is_ed25519() && !unlocked_addresses.contains(&required_address) {
+                return Err(PreparedTransactionError::MissingUnlock {
+                    output_id,
+                    address: required_address,
+                });
+            }
+        }
+
+        verify_balances(
+            self.inputs_data.iter().map(|input| (input.output_id(), &input.output)),
+            essence.outputs(),
+            current_time,
+        )?;
+
+        // Validation of the remainder.
+        if let Some(remainder) = &self.remainder {
+            if !essence.outputs().contains(&remainder.output) {
+                return Err(PreparedTransactionError::RemainderNotFound);
+            }
+
+            if remainder
+                .output
+                .unlock_conditions()
+                .and_then(|u| u.address())
+                .map(|a| a.address())
+                != Some(&remainder.address)
+            {
+                return Err(PreparedTransactionError::RemainderAddressMismatch);
+            }
+
+            remainder
+                .output
+                .verify_storage_deposit(
+                    *protocol_parameters.rent_structure(),
+                    protocol_parameters.token_supply(),
+                )
+                .map_err(PreparedTransactionError::RemainderStorageDeposit)?;
+        }
+
+        Ok(())
+    }
+
+    // An alias output is state transitioned if its state index is increased, otherwise it's governance transitioned.
+    fn alias_transition(&self, input: &InputSigningData) -> Option<AliasTransition> {
+        let Output::Alias(alias_input) = &input.output else {
+            return None;
+        };
+        let alias_id = alias_input.alias_id_non_null(input.output_id());
+        let TransactionEssence::Regular(essence) = &self.essence;
+
+        let state_transition = essence.outputs().iter().any(|output| match output {
+            Output::Alias(alias_output) => {
+                alias_output.alias_id_non_null(input.output_id()) == alias_id
+                    && alias_output.state_index() > alias_input.state_index()
+            }
+            _ => false,
+        });
+
+        Some(if state_transition {
+            AliasTransition::State
+        } else {
+            AliasTransition::Governance
+        })
+    }
+}
+
+/// Errors returned by [`PreparedTransactionData::verify_semantic()`], describing which invariant of the prepared
+/// transaction doesn't hold.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum PreparedTransactionError {
+    /// The network id of the essence doesn't match the protocol parameters.
+    #[error("network id mismatch: expected {expected}, found {found}")]
+    NetworkIdMismatch {
+        /// The network id of the protocol parameters.
+        expected: u64,
+        /// The network id of the essence.
+        found: u64,
+    },
+    /// The essence and the inputs data have a different number of inputs.
+    #[error("inputs count mismatch: {essence} in the essence, {inputs_data} in the inputs data")]
+    InputsCountMismatch {
+        /// The number of inputs in the essence.
+        essence: usize,
+        /// The number of inputs data.
+        inputs_data: usize,
+    },
+    /// The input at the given index doesn't match the inputs data.
+    #[error("input {0} doesn't match the inputs data")]
+    InputMismatch(usize),
+    /// The inputs commitment of the essence doesn't match the inputs data.
+    #[error("inputs commitment mismatch")]
+    InputsCommitmentMismatch,
+    /// The input can't be unlocked by a transaction.
+    #[error("unsupported input {0}")]
+    UnsupportedInput(OutputId),
+    /// The address required to unlock the input isn't unlocked by another input.
+    #[error("input {output_id} requires {address:?} which isn't unlocked by another input")]
+    MissingUnlock {
+        /// The input that can't be unlocked.
+        output_id: OutputId,
+        /// The address required to unlock the input.
+        address: Address,
+    },
+    /// The amounts of the inputs and outputs don't balance.
+    #[error(transparent)]
+    Balance(#[from] BalanceError),
+    /// The remainder output isn't part of the essence outputs.
+    #[error("remainder output not found in the outputs")]
+    RemainderNotFound,
+    /// The remainder output isn't owned by the remainder address.
+    #[error("remainder output isn't owned by the remainder address")]
+    RemainderAddressMismatch,
+    /// The remainder output doesn't cover its storage deposit.
+    #[error("remainder storage deposit: {0}")]
+    RemainderStorageDeposit(Error),
+}
+
 /// PreparedTransactionData Dto
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -288,6 +288,7 @@ impl ClientBuilder {
                 receiver: RwLock::new(mqtt_event_rx),
             },
             request_pool: crate::client::request_pool::RequestPool::new(self.max_parallel_api_requests),
+            clock: std::sync::RwLock::new(Arc::new(crate::client::clock::SystemClock)),
         });
 
         client_inner.sync_nodes(&nodes, ignore_node_health).await?;
@@ -330,6 +331,7 @@ impl ClientBuilder {
                     receiver: RwLock::new(mqtt_event_rx),
                 },
                 last_sync: tokio::sync::Mutex::new(None),
+                clock: std::sync::RwLock::new(Arc::new(crate::client::clock::SystemClock)),
             }),
         };
 
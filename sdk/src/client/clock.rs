@@ -0,0 +1,63 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Time sources of the client, so time dependent logic can be tested deterministically.
+
+use core::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+use std::sync::Arc;
+
+/// A source of the current time.
+pub trait Clock: core::fmt::Debug + Send + Sync {
+    /// Returns the current time as duration since the unix epoch.
+    fn now(&self) -> Duration;
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> Duration {
+        (**self).now()
+    }
+}
+
+/// The system time, used by default.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        crate::utils::unix_timestamp_now()
+    }
+}
+
+/// A clock that only changes when it's told to, with millisecond precision.
+#[derive(Debug, Default)]
+pub struct MockClock {
+    millis: AtomicU64,
+}
+
+impl MockClock {
+    /// Creates a new [`MockClock`] set to the given time since the unix epoch.
+    pub fn new(now: Duration) -> Self {
+        Self {
+            millis: AtomicU64::new(now.as_millis() as u64),
+        }
+    }
+
+    /// Sets the time since the unix epoch.
+    pub fn set(&self, now: Duration) {
+        self.millis.store(now.as_millis() as u64, Ordering::SeqCst);
+    }
+
+    /// Moves the time forward.
+    pub fn advance(&self, duration: Duration) {
+        self.millis.fetch_add(duration.as_millis() as u64, Ordering::SeqCst);
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Duration {
+        Duration::from_millis(self.millis.load(Ordering::SeqCst))
+    }
+}
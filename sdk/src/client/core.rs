@@ -20,9 +20,9 @@ use crate::client::constants::CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS;
 use crate::{
     client::{
         builder::{ClientBuilder, NetworkInfo},
+        clock::Clock,
         error::Result,
         node_manager::NodeManager,
-        Error,
     },
     types::block::{address::Hrp, output::RentStructure, protocol::ProtocolParameters},
 };
@@ -60,6 +60,7 @@ pub struct ClientInner {
     pub(crate) last_sync: tokio::sync::Mutex<Option<u32>>,
     #[cfg(not(target_family = "wasm"))]
     pub(crate) request_pool: RequestPool,
+    pub(crate) clock: std::sync::RwLock<Arc<dyn Clock>>,
 }
 
 #[cfg(not(target_family = "wasm"))]
@@ -114,7 +115,7 @@ impl ClientInner {
         // create invalid transactions/blocks.
         #[cfg(target_family = "wasm")]
         {
-            let current_time = crate::utils::unix_timestamp_now().as_secs() as u32;
+            let current_time = self.unix_timestamp_now().as_secs() as u32;
             if let Some(last_sync) = *self.last_sync.lock().await {
                 if current_time < last_sync {
                     return Ok(self.network_info.read().await.clone());
@@ -129,6 +130,17 @@ impl ClientInner {
         Ok(self.network_info.read().await.clone())
     }
 
+    /// Returns the current time of the client's [`Clock`], as duration since the unix epoch.
+    pub fn unix_timestamp_now(&self) -> Duration {
+        self.clock.read().expect("clock lock poisoned").now()
+    }
+
+    /// Replaces the [`Clock`] of the client, for example with a [`MockClock`](crate::client::clock::MockClock) in
+    /// tests.
+    pub fn set_clock(&self, clock: impl Clock + 'static) {
+        *self.clock.write().expect("clock lock poisoned") = Arc::new(clock);
+    }
+
     /// Gets the protocol parameters of the node we're connecting to.
     pub async fn get_protocol_parameters(&self) -> Result<ProtocolParameters> {
         Ok(self.get_network_info().await?.protocol_parameters)
@@ -199,14 +211,7 @@ impl ClientInner {
 
     /// Validates if a bech32 HRP matches the one from the connected network.
     pub async fn bech32_hrp_matches(&self, bech32_hrp: &Hrp) -> Result<()> {
-        let expected = self.get_bech32_hrp().await?;
-        if bech32_hrp != &expected {
-            return Err(Error::Bech32HrpMismatch {
-                provided: bech32_hrp.to_string(),
-                expected: expected.to_string(),
-            });
-        };
-        Ok(())
+        Ok(bech32_hrp.matches(&self.get_bech32_hrp().await?)?)
     }
 
     /// Resize the client's request pool
@@ -12,7 +12,10 @@ use serde::{
 };
 
 use crate::{
-    client::{api::input_selection::Error as InputSelectionError, node_api::indexer::QueryParameter},
+    client::{
+        api::input_selection::Error as InputSelectionError,
+        node_api::{error::Error as NodeApiError, indexer::QueryParameter},
+    },
     types::block::semantic::ConflictReason,
 };
 
@@ -38,7 +41,7 @@ pub enum Error {
     Blake2b256(&'static str),
     /// Block types error
     #[error("{0}")]
-    Block(#[from] crate::types::block::Error),
+    Block(crate::types::block::Error),
     /// The wallet account has enough funds, but split on too many outputs
     #[error("the wallet account has enough funds, but split on too many outputs: {0}, max. is 128, consolidate them")]
     ConsolidationRequired(usize),
@@ -56,11 +59,16 @@ pub enum Error {
     /// Invalid amount in API response
     #[error("invalid amount in API response: {0}")]
     InvalidAmount(String),
+    /// Invalid chunk of prepared transaction data
+    #[error("invalid chunk: {0}")]
+    InvalidChunk(String),
     /// Invalid mnemonic error
     #[error("invalid mnemonic {0}")]
     InvalidMnemonic(String),
     /// The transaction essence is too large
-    #[error("the transaction essence is too large. Its length is {length}, max length is {max_length}")]
+    #[error(
+        "the transaction essence is too large. Its length is {length}, max length is {max_length}; use fewer inputs or outputs, e.g. by splitting it into multiple transactions"
+    )]
     InvalidRegularTransactionEssenceLength {
         /// The found length.
         length: usize,
@@ -68,7 +76,9 @@ pub enum Error {
         max_length: usize,
     },
     /// The transaction payload is too large
-    #[error("the transaction payload is too large. Its length is {length}, max length is {max_length}")]
+    #[error(
+        "the transaction payload is too large. Its length is {length}, max length is {max_length}; use fewer inputs or outputs, e.g. by splitting it into multiple transactions"
+    )]
     InvalidTransactionPayloadLength {
         /// The found length.
         length: usize,
@@ -191,6 +201,19 @@ pub enum Error {
     Stronghold(#[from] crate::client::stronghold::Error),
 }
 
+impl From<crate::types::block::Error> for Error {
+    fn from(error: crate::types::block::Error) -> Self {
+        match error {
+            // Mismatching HRPs are always reported as the client error, whether the check was done on the address or
+            // against the connected network
+            crate::types::block::Error::Bech32HrpMismatch { provided, expected } => {
+                Self::Bech32HrpMismatch { provided, expected }
+            }
+            error => Self::Block(error),
+        }
+    }
+}
+
 // Serialize type with Display error
 impl Serialize for Error {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -211,3 +234,44 @@ impl Serialize for Error {
         seq.end()
     }
 }
+
+/// Error of posting a block, split by whether posting the block again could succeed.
+#[derive(Debug, thiserror::Error)]
+pub enum PostBlockError {
+    /// The block was rejected by the node, e.g. because of invalid parents or an invalid signature, or it couldn't be
+    /// built. Posting the same block again won't help.
+    #[error("block rejected: {0}")]
+    Validation(Box<Error>),
+    /// The block couldn't be delivered, e.g. because of a timeout or an unavailable node. Posting it again could help.
+    #[error("block couldn't be posted: {0}")]
+    Network(Box<Error>),
+}
+
+impl PostBlockError {
+    /// Returns whether posting the block again could succeed.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::Network(_))
+    }
+
+    /// Returns the underlying error.
+    pub fn into_inner(self) -> Error {
+        match self {
+            Self::Validation(error) | Self::Network(error) => *error,
+        }
+    }
+}
+
+impl From<Error> for PostBlockError {
+    fn from(error: Error) -> Self {
+        match &error {
+            // Only failures to reach a node, timeouts, rate limiting and server errors can go away by posting again.
+            Error::Node(NodeApiError::Reqwest(_)) | Error::HealthyNodePoolEmpty => Self::Network(Box::new(error)),
+            Error::Node(NodeApiError::ResponseError { code, .. })
+                if *code == 408 || *code == 429 || (500..600).contains(code) =>
+            {
+                Self::Network(Box::new(error))
+            }
+            _ => Self::Validation(Box::new(error)),
+        }
+    }
+}
@@ -37,6 +37,7 @@ macro_rules! lazy_static {
 
 pub mod api;
 pub mod builder;
+pub mod clock;
 pub mod constants;
 pub mod core;
 pub mod error;
@@ -5,11 +5,24 @@
 
 pub mod routes;
 
+use std::collections::HashMap;
+
+use futures::{StreamExt, TryStreamExt};
+
 use crate::{
-    client::{Client, Result},
-    types::block::output::{OutputId, OutputMetadata, OutputWithMetadata},
+    client::{node_api::error::Error as NodeApiError, Client, Error, Result},
+    types::{
+        api::core::response::BlockMetadataResponse,
+        block::{
+            output::{OutputId, OutputMetadata, OutputWithMetadata},
+            payload::transaction::TransactionId,
+        },
+    },
 };
 
+/// Maximum number of concurrent requests of the batch methods.
+const MAX_PARALLEL_REQUESTS: usize = 10;
+
 impl Client {
     /// Request outputs by their output ID in parallel
     pub async fn get_outputs(&self, output_ids: &[OutputId]) -> Result<Vec<OutputWithMetadata>> {
@@ -38,4 +51,24 @@ impl Client {
                 .collect(),
         )
     }
+
+    /// Requests the metadata of the blocks which included the given transactions, with at most
+    /// `MAX_PARALLEL_REQUESTS` concurrent requests. Transactions which aren't included yet are missing from the map.
+    pub async fn get_included_blocks_metadata(
+        &self,
+        transaction_ids: &[TransactionId],
+    ) -> Result<HashMap<TransactionId, BlockMetadataResponse>> {
+        futures::stream::iter(transaction_ids)
+            .map(|transaction_id| async move {
+                match self.get_included_block_metadata(transaction_id).await {
+                    Ok(metadata) => Ok(Some((*transaction_id, metadata))),
+                    Err(Error::Node(NodeApiError::NotFound(_))) => Ok(None),
+                    Err(e) => Err(e),
+                }
+            })
+            .buffer_unordered(MAX_PARALLEL_REQUESTS)
+            .try_filter_map(|res| async move { Ok(res) })
+            .try_collect()
+            .await
+    }
 }
@@ -1,7 +1,7 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-//! Custom plugin call
+//! Custom plugin and raw endpoint calls
 
 use core::str::FromStr;
 
@@ -37,4 +37,16 @@ impl ClientInner {
             )),
         }
     }
+
+    /// Sends a GET request to an arbitrary node endpoint, like `api/core/v2/info`, with the node selection, auth and
+    /// timeout of the client. Meant for endpoints that aren't supported by a typed method yet.
+    pub async fn get_json(&self, path: &str, query: Option<&str>) -> Result<serde_json::Value> {
+        self.get_request(path, query, false, false).await
+    }
+
+    /// Sends a POST request with a JSON body to an arbitrary node endpoint, with the node selection, auth and timeout of
+    /// the client. Meant for endpoints that aren't supported by a typed method yet.
+    pub async fn post_json(&self, path: &str, body: serde_json::Value) -> Result<serde_json::Value> {
+        self.post_request_json(path, body, true).await
+    }
 }
@@ -1,24 +1,47 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::hash::Hash;
+use std::{collections::BTreeMap, hash::Hash};
 
 use serde::{Deserialize, Serialize};
 
 use crate::Url;
 
 /// Node authentication object.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[serde(rename_all = "camelCase")]
 pub struct NodeAuth {
     /// JWT.
     pub jwt: Option<String>,
     /// Username and password.
     pub basic_auth_name_pwd: Option<(String, String)>,
+    /// Custom headers added to every request.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    /// PEM encoded root CA certificate which replaces the system's root certificates for the node, so its TLS
+    /// certificate has to be issued by it, or be it. This restricts the trust anchors, the node's certificate itself
+    /// isn't pinned. Requires the `tls` feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root_certificate: Option<String>,
+}
+
+// Credentials must not end up in logs.
+impl core::fmt::Debug for NodeAuth {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("NodeAuth")
+            .field("jwt", &self.jwt.as_ref().map(|_| "<omitted>"))
+            .field(
+                "basic_auth_name_pwd",
+                &self.basic_auth_name_pwd.as_ref().map(|(name, _)| (name, "<omitted>")),
+            )
+            .field("headers", &self.headers.keys().collect::<Vec<_>>())
+            .field("root_certificate", &self.root_certificate)
+            .finish()
+    }
 }
 
 /// Node definition.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct Node {
     /// Node url.
     pub url: Url,
@@ -30,6 +53,17 @@ pub struct Node {
     pub disabled: bool,
 }
 
+// The url can contain basic auth credentials or tokens, which must not end up in logs.
+impl core::fmt::Debug for Node {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Node")
+            .field("url", &redact_url(&self.url))
+            .field("auth", &self.auth)
+            .field("disabled", &self.disabled)
+            .finish()
+    }
+}
+
 impl From<Url> for Node {
     fn from(url: Url) -> Self {
         Self {
@@ -41,7 +75,7 @@ impl From<Url> for Node {
 }
 
 /// NodeDto enum to accept just a single url or also provided auth options or set if the node should be disabled.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 #[serde(untagged)]
 pub enum NodeDto {
     /// Node url
@@ -50,6 +84,15 @@ pub enum NodeDto {
     Node(Node),
 }
 
+impl core::fmt::Debug for NodeDto {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Url(url) => f.debug_tuple("Url").field(&redact_url(url)).finish(),
+            Self::Node(node) => f.debug_tuple("Node").field(node).finish(),
+        }
+    }
+}
+
 impl From<NodeDto> for Node {
     fn from(node: NodeDto) -> Self {
         match node {
@@ -67,3 +110,30 @@ impl From<&NodeDto> for Node {
         }
     }
 }
+
+// Query parameters which can carry credentials, compared case-insensitively.
+const SENSITIVE_QUERY_PARAMETERS: [&str; 6] = ["token", "access_token", "api_key", "apikey", "jwt", "password"];
+
+/// Removes basic auth credentials and credentials in query parameters from an url, so it can be logged or returned in
+/// errors.
+pub(crate) fn redact_url(url: &url::Url) -> url::Url {
+    let mut url = url.clone();
+    // Only fails for urls that can't have credentials in the first place
+    url.set_username("").ok();
+    url.set_password(None).ok();
+
+    if url.query().is_some() {
+        let query_pairs = url.query_pairs().into_owned().collect::<Vec<_>>();
+        url.query_pairs_mut()
+            .clear()
+            .extend_pairs(query_pairs.iter().map(|(name, value)| {
+                if SENSITIVE_QUERY_PARAMETERS.contains(&name.to_lowercase().as_str()) {
+                    (name.as_str(), "redacted")
+                } else {
+                    (name.as_str(), value.as_str())
+                }
+            }));
+    }
+
+    url
+}
@@ -3,6 +3,7 @@
 
 #[cfg(not(target_family = "wasm"))]
 use {
+    super::node::redact_url,
     crate::types::api::core::response::InfoResponse,
     std::{collections::HashSet, time::Duration},
     tokio::time::sleep,
@@ -83,7 +84,7 @@ impl ClientInner {
                             }
                         }
                     } else {
-                        log::warn!("{} is not healthy: {:?}", node.url, info);
+                        log::warn!("{} is not healthy: {:?}", redact_url(&node.url), info);
                     }
                 }
                 Err(err) => {
@@ -19,7 +19,7 @@ use iota_ledger_nano::{
 use packable::{error::UnexpectedEOF, unpacker::SliceUnpacker, Packable, PackableExt};
 use tokio::sync::Mutex;
 
-use super::{GenerateAddressOptions, SecretManage, SecretManagerConfig};
+use super::{GenerateAddressOptions, SecretManage, SecretManagerConfig, SignerCapabilities};
 use crate::{
     client::secret::{
         is_alias_transition,
@@ -27,7 +27,7 @@ use crate::{
         LedgerNanoStatus, PreparedTransactionData,
     },
     types::block::{
-        address::{Address, AliasAddress, Ed25519Address, NftAddress},
+        address::{Address, AliasAddress, Bech32Address, Ed25519Address, Hrp, NftAddress},
         output::Output,
         payload::transaction::{TransactionEssence, TransactionPayload},
         signature::{Ed25519Signature, Signature},
@@ -236,6 +236,16 @@ impl SecretManage for LedgerSecretManager {
         Err(Error::UnsupportedOperation.into())
     }
 
+    async fn capabilities(&self) -> Result<SignerCapabilities, Self::Error> {
+        let ledger_nano_status = self.get_ledger_nano_status().await;
+
+        Ok(SignerCapabilities {
+            secp256k1_ecdsa: false,
+            blind_signing: ledger_nano_status.blind_signing_enabled(),
+            buffer_size: ledger_nano_status.buffer_size(),
+        })
+    }
+
     async fn sign_transaction_essence(
         &self,
         prepared_transaction: &PreparedTransactionData,
@@ -440,6 +450,66 @@ pub fn needs_blind_signing(prepared_transaction: &PreparedTransactionData, buffe
     total_size > buffer_size
 }
 
+/// What the Ledger Nano app displays for a transaction before it's signed, see
+/// [`PreparedTransactionData::ledger_display_summary()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LedgerDisplay {
+    /// Only the essence hash is displayed, because the transaction needs blind signing.
+    BlindSigning {
+        /// The essence hash.
+        essence_hash: [u8; 32],
+    },
+    /// The outputs are displayed one by one, in the order of the essence.
+    Outputs(Vec<LedgerDisplayOutput>),
+}
+
+/// An output as displayed by the Ledger Nano app.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LedgerDisplayOutput {
+    /// The address the amount is sent to.
+    pub address: Bech32Address,
+    /// The amount in the smallest unit of the base coin.
+    pub amount: u64,
+    /// Whether the output is the remainder, which the device checks against its own bip32 chain.
+    pub remainder: bool,
+}
+
+impl PreparedTransactionData {
+    /// Returns what the Ledger Nano app displays for this transaction, so it can be shown to the user before the
+    /// confirmation is requested on the device. The `buffer_size` of the device can be read from the
+    /// [`LedgerNanoStatus`].
+    pub fn ledger_display_summary(&self, hrp: Hrp, buffer_size: usize) -> LedgerDisplay {
+        if needs_blind_signing(self, buffer_size) {
+            return LedgerDisplay::BlindSigning {
+                essence_hash: self.essence.hash(),
+            };
+        }
+
+        let TransactionEssence::Regular(essence) = &self.essence;
+        // The remainder is only sent to the device along with its chain, the first equal output is flagged as it
+        let remainder_index = self
+            .remainder
+            .as_ref()
+            .filter(|remainder| remainder.chain.is_some())
+            .and_then(|remainder| essence.outputs().iter().position(|output| output == &remainder.output));
+
+        LedgerDisplay::Outputs(
+            essence
+                .outputs()
+                .iter()
+                .enumerate()
+                // Only basic outputs with a single address unlock condition can be displayed, see
+                // `needs_blind_signing()`
+                .map(|(index, output)| LedgerDisplayOutput {
+                    address: Bech32Address::new(hrp, *output.as_basic().address()),
+                    amount: output.amount(),
+                    remainder: remainder_index == Some(index),
+                })
+                .collect(),
+        )
+    }
+}
+
 impl LedgerSecretManager {
     /// Creates a [`LedgerSecretManager`].
     ///
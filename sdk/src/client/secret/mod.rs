@@ -39,7 +39,7 @@ use self::mnemonic::MnemonicSecretManager;
 use self::private_key::PrivateKeySecretManager;
 #[cfg(feature = "stronghold")]
 use self::stronghold::StrongholdSecretManager;
-pub use self::types::{GenerateAddressOptions, LedgerNanoStatus};
+pub use self::types::{GenerateAddressOptions, LedgerNanoStatus, PublicDescriptor, SignerCapabilities};
 #[cfg(feature = "stronghold")]
 use crate::client::secret::types::StrongholdDto;
 use crate::{
@@ -86,6 +86,35 @@ pub trait SecretManage: Send + Sync {
         options: impl Into<Option<GenerateAddressOptions>> + Send,
     ) -> Result<Vec<EvmAddress>, Self::Error>;
 
+    /// Exports a [`PublicDescriptor`] with the public and internal addresses of the given range, which can be used to
+    /// set up a watch-only wallet. Ledger devices are not prompted to display the addresses.
+    async fn export_public_descriptor(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+    ) -> Result<PublicDescriptor, Self::Error> {
+        let public_addresses = self
+            .generate_ed25519_addresses(coin_type, account_index, address_indexes.clone(), None)
+            .await?;
+        let internal_addresses = self
+            .generate_ed25519_addresses(
+                coin_type,
+                account_index,
+                address_indexes.clone(),
+                GenerateAddressOptions::internal(),
+            )
+            .await?;
+
+        Ok(PublicDescriptor {
+            coin_type,
+            account_index,
+            address_index_start: address_indexes.start,
+            public_addresses,
+            internal_addresses,
+        })
+    }
+
     /// Signs msg using the given [`Bip44`] using Ed25519.
     async fn sign_ed25519(&self, msg: &[u8], chain: Bip44) -> Result<Ed25519Signature, Self::Error>;
 
@@ -96,6 +125,12 @@ pub trait SecretManage: Send + Sync {
         chain: Bip44,
     ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::RecoverableSignature), Self::Error>;
 
+    /// Returns the [`SignerCapabilities`] of the secret manager, so it can be checked that a transaction can be signed
+    /// before a device is asked to sign it.
+    async fn capabilities(&self) -> Result<SignerCapabilities, Self::Error> {
+        Ok(SignerCapabilities::default())
+    }
+
     /// Signs `essence_hash` using the given `chain`, returning an [`Unlock`].
     async fn signature_unlock(&self, essence_hash: &[u8; 32], chain: Bip44) -> Result<Unlock, Self::Error> {
         Ok(Unlock::Signature(SignatureUnlock::new(Signature::from(
@@ -423,6 +458,24 @@ impl SecretManage for SecretManager {
         }
     }
 
+    async fn capabilities(&self) -> Result<SignerCapabilities, Self::Error> {
+        match self {
+            #[cfg(feature = "stronghold")]
+            Self::Stronghold(secret_manager) => Ok(secret_manager.capabilities().await?),
+            #[cfg(feature = "ledger_nano")]
+            Self::LedgerNano(secret_manager) => Ok(secret_manager.capabilities().await?),
+            Self::Mnemonic(secret_manager) => secret_manager.capabilities().await,
+            #[cfg(feature = "private_key_secret_manager")]
+            Self::PrivateKey(secret_manager) => secret_manager.capabilities().await,
+            // Nothing can be signed with it
+            Self::Placeholder => Ok(SignerCapabilities {
+                secp256k1_ecdsa: false,
+                blind_signing: false,
+                buffer_size: None,
+            }),
+        }
+    }
+
     async fn sign_transaction_essence(
         &self,
         prepared_transaction_data: &PreparedTransactionData,
@@ -16,7 +16,7 @@ use crypto::{
 };
 use zeroize::{Zeroize, Zeroizing};
 
-use super::{GenerateAddressOptions, SecretManage};
+use super::{GenerateAddressOptions, SecretManage, SignerCapabilities};
 use crate::{
     client::{api::PreparedTransactionData, Error},
     types::block::{
@@ -79,6 +79,13 @@ impl SecretManage for PrivateKeySecretManager {
         Err(Error::SecretManagerMismatch)
     }
 
+    async fn capabilities(&self) -> Result<SignerCapabilities, Self::Error> {
+        Ok(SignerCapabilities {
+            secp256k1_ecdsa: false,
+            ..Default::default()
+        })
+    }
+
     async fn sign_transaction_essence(
         &self,
         prepared_transaction_data: &PreparedTransactionData,
@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     types::{
         block::{
-            address::Address,
+            address::{Address, Ed25519Address},
             output::{dto::OutputDto, Output, OutputId, OutputMetadata},
         },
         TryFromDto, ValidationParams,
@@ -70,6 +70,49 @@ impl GenerateAddressOptions {
     }
 }
 
+/// Public information to watch the addresses of an account without any private material.
+///
+/// Ed25519 keys are derived with hardened SLIP-10 derivation only, so no extended public key exists from which further
+/// addresses could be derived. The descriptor therefore contains the addresses of a fixed range of address indexes.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicDescriptor {
+    /// The coin type.
+    pub coin_type: u32,
+    /// The account index.
+    pub account_index: u32,
+    /// The first address index of the addresses.
+    pub address_index_start: u32,
+    /// The public addresses, starting at `address_index_start`.
+    pub public_addresses: Vec<Ed25519Address>,
+    /// The internal (change) addresses, starting at `address_index_start`.
+    pub internal_addresses: Vec<Ed25519Address>,
+}
+
+/// What a secret manager supports, see [`SecretManage::capabilities()`](super::SecretManage::capabilities).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignerCapabilities {
+    /// Whether Secp256k1 ECDSA signatures, which are used for EVM addresses, are supported.
+    pub secp256k1_ecdsa: bool,
+    /// Whether transactions can be signed without showing their details to the user. Ledger Nano devices can only show
+    /// transactions with basic outputs to ed25519 addresses that fit into their buffer, signing other transactions
+    /// requires blind signing to be enabled in the app.
+    pub blind_signing: bool,
+    /// The size of the buffer for transactions shown to the user, if the secret manager has one.
+    pub buffer_size: Option<usize>,
+}
+
+impl Default for SignerCapabilities {
+    fn default() -> Self {
+        Self {
+            secp256k1_ecdsa: true,
+            blind_signing: true,
+            buffer_size: None,
+        }
+    }
+}
+
 /// The Ledger device status.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct LedgerApp {
@@ -50,4 +50,9 @@ pub trait StorageAdapter: std::fmt::Debug + Send + Sync {
 
     /// Removes a record from the storage.
     async fn delete(&self, key: &str) -> Result<(), Self::Error>;
+
+    /// Flushes pending writes to the underlying storage, if the adapter buffers them.
+    async fn flush(&self) -> Result<(), Self::Error> {
+        Ok(())
+    }
 }
@@ -40,6 +40,17 @@ impl Hrp {
             len: len as _,
         }
     }
+
+    /// Checks that the human readable part is the expected one, see [`Bech32Address::hrp_matches()`].
+    pub(crate) fn matches(&self, expected: &Self) -> Result<(), Error> {
+        if self != expected {
+            return Err(Error::Bech32HrpMismatch {
+                provided: self.to_string(),
+                expected: expected.to_string(),
+            });
+        }
+        Ok(())
+    }
 }
 
 impl FromStr for Hrp {
@@ -198,6 +209,18 @@ impl Bech32Address {
     pub fn try_from_str(address: impl AsRef<str>) -> Result<Self, Error> {
         Self::from_str(address.as_ref())
     }
+
+    /// Parses a bech32 address string and checks that its human readable part is the expected one.
+    pub fn try_from_str_with_hrp(address: impl AsRef<str>, hrp: impl ConvertTo<Hrp>) -> Result<Self, Error> {
+        let address = Self::from_str(address.as_ref())?;
+        address.hrp_matches(&hrp.convert()?)?;
+        Ok(address)
+    }
+
+    /// Checks that the human readable part of the address is the expected one.
+    pub fn hrp_matches(&self, hrp: &Hrp) -> Result<(), Error> {
+        self.hrp.matches(hrp)
+    }
 }
 
 impl core::fmt::Display for Bech32Address {
@@ -13,8 +13,11 @@ use packable::{
 use crate::types::block::{
     address::{Address, Ed25519Address},
     output::{
-        unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition},
-        BasicOutputBuilder, NativeTokens, Output, OutputId,
+        unlock_condition::{
+            AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition,
+            TimelockUnlockCondition,
+        },
+        BasicOutputBuilder, Feature, NativeTokens, Output, OutputId,
     },
     payload::milestone::MilestoneIndex,
     BlockId, Error,
@@ -173,6 +176,11 @@ impl MinimumStorageDepositBasicOutput {
         self
     }
 
+    pub fn with_features(mut self, features: impl IntoIterator<Item = impl Into<Feature>>) -> Self {
+        self.builder = self.builder.with_features(features);
+        self
+    }
+
     pub fn with_storage_deposit_return(mut self) -> Result<Self, Error> {
         self.builder = self
             .builder
@@ -192,6 +200,11 @@ impl MinimumStorageDepositBasicOutput {
         Ok(self)
     }
 
+    pub fn with_timelock(mut self) -> Result<Self, Error> {
+        self.builder = self.builder.add_unlock_condition(TimelockUnlockCondition::new(1)?);
+        Ok(self)
+    }
+
     pub fn finish(self) -> Result<u64, Error> {
         Ok(self.builder.finish_output(self.token_supply)?.rent_cost(&self.config))
     }
@@ -216,167 +216,304 @@ impl<'a> ValidationContext<'a> {
     }
 }
 
-///
-pub fn semantic_validation(
-    mut context: ValidationContext<'_>,
-    inputs: &[(&OutputId, &Output)],
-    unlocks: &Unlocks,
-) -> Result<ConflictReason, Error> {
-    // Validation of the inputs commitment.
-    if context.essence.inputs_commitment() != &context.inputs_commitment {
-        return Ok(ConflictReason::InputsCommitmentsMismatch);
-    }
+/// The reasons why the amounts of a transaction don't balance, see [`verify_balances()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BalanceError {
+    /// An input or output of a kind that can't be part of a transaction.
+    UnsupportedOutputKind(u8),
+    /// The input is still time locked.
+    TimelockNotExpired(OutputId),
+    /// The storage deposit returns to an address overflowed.
+    StorageDepositReturnOverflow,
+    /// The base coin amount of the inputs overflowed.
+    ConsumedAmountOverflow,
+    /// The amount of a native token of the inputs overflowed.
+    ConsumedNativeTokensAmountOverflow(TokenId),
+    /// The base coin amount of the outputs overflowed.
+    CreatedAmountOverflow,
+    /// The amount of a native token of the outputs overflowed.
+    CreatedNativeTokensAmountOverflow(TokenId),
+    /// A storage deposit return isn't fulfilled by the outputs.
+    StorageDepositReturnUnfulfilled(Address),
+    /// The base coin amount of the inputs doesn't match the one of the outputs.
+    AmountMismatch {
+        /// The amount of the inputs.
+        input: u64,
+        /// The amount of the outputs.
+        output: u64,
+    },
+    /// The outputs have more of a native token than the inputs, without its foundry being an output.
+    NativeTokenAmountMismatch {
+        /// The native token.
+        token_id: TokenId,
+        /// The amount of the inputs.
+        input: U256,
+        /// The amount of the outputs.
+        output: U256,
+    },
+    /// The transaction has more distinct native tokens than allowed.
+    NativeTokensCountExceeded(usize),
+}
 
-    // Validation of inputs.
-    for ((output_id, consumed_output), unlock) in inputs.iter().zip(unlocks.iter()) {
-        let (conflict, amount, consumed_native_tokens, unlock_conditions) = match consumed_output {
-            Output::Basic(output) => (
-                output.unlock(output_id, unlock, inputs, &mut context),
-                output.amount(),
-                output.native_tokens(),
-                output.unlock_conditions(),
-            ),
-            Output::Alias(output) => (
-                output.unlock(output_id, unlock, inputs, &mut context),
-                output.amount(),
-                output.native_tokens(),
-                output.unlock_conditions(),
-            ),
-            Output::Foundry(output) => (
-                output.unlock(output_id, unlock, inputs, &mut context),
-                output.amount(),
-                output.native_tokens(),
-                output.unlock_conditions(),
-            ),
-            Output::Nft(output) => (
-                output.unlock(output_id, unlock, inputs, &mut context),
-                output.amount(),
-                output.native_tokens(),
-                output.unlock_conditions(),
+impl fmt::Display for BalanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedOutputKind(kind) => write!(f, "unsupported output kind {kind}"),
+            Self::TimelockNotExpired(output_id) => write!(f, "input {output_id} is time locked"),
+            Self::StorageDepositReturnOverflow => write!(f, "storage deposit return overflow"),
+            Self::ConsumedAmountOverflow => write!(f, "consumed amount overflow"),
+            Self::ConsumedNativeTokensAmountOverflow(token_id) => {
+                write!(f, "consumed native token amount overflow for {token_id}")
+            }
+            Self::CreatedAmountOverflow => write!(f, "created amount overflow"),
+            Self::CreatedNativeTokensAmountOverflow(token_id) => {
+                write!(f, "created native token amount overflow for {token_id}")
+            }
+            Self::StorageDepositReturnUnfulfilled(address) => {
+                write!(f, "storage deposit return to {address:?} isn't fulfilled")
+            }
+            Self::AmountMismatch { input, output } => {
+                write!(f, "amount mismatch: {input} in the inputs, {output} in the outputs")
+            }
+            Self::NativeTokenAmountMismatch {
+                token_id,
+                input,
+                output,
+            } => write!(
+                f,
+                "native token amount mismatch for {token_id}: {input} in the inputs, {output} in the outputs"
             ),
-            _ => return Err(Error::UnsupportedOutputKind(consumed_output.kind())),
-        };
+            Self::NativeTokensCountExceeded(count) => {
+                write!(f, "too many native tokens: {count}, max is {}", NativeTokens::COUNT_MAX)
+            }
+        }
+    }
+}
 
-        if let Err(conflict) = conflict {
-            return Ok(conflict);
+#[cfg(feature = "std")]
+impl std::error::Error for BalanceError {}
+
+impl BalanceError {
+    fn into_conflict_reason(self) -> Result<ConflictReason, Error> {
+        match self {
+            Self::UnsupportedOutputKind(kind) => Err(Error::UnsupportedOutputKind(kind)),
+            Self::TimelockNotExpired(_) => Ok(ConflictReason::TimelockNotExpired),
+            Self::StorageDepositReturnOverflow => Err(Error::StorageDepositReturnOverflow),
+            Self::ConsumedAmountOverflow => Err(Error::ConsumedAmountOverflow),
+            Self::ConsumedNativeTokensAmountOverflow(_) => Err(Error::ConsumedNativeTokensAmountOverflow),
+            Self::CreatedAmountOverflow => Err(Error::CreatedAmountOverflow),
+            Self::CreatedNativeTokensAmountOverflow(_) => Err(Error::CreatedNativeTokensAmountOverflow),
+            Self::StorageDepositReturnUnfulfilled(_) => Ok(ConflictReason::StorageDepositReturnUnfulfilled),
+            Self::AmountMismatch { .. } => Ok(ConflictReason::CreatedConsumedAmountMismatch),
+            Self::NativeTokenAmountMismatch { .. } | Self::NativeTokensCountExceeded(_) => {
+                Ok(ConflictReason::InvalidNativeTokens)
+            }
         }
+    }
+}
+
+/// The base coin and native token amounts of the inputs and outputs of a transaction, see [`verify_balances()`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Balances {
+    /// The base coin amount of the inputs.
+    pub input_amount: u64,
+    /// The native token amounts of the inputs.
+    pub input_native_tokens: BTreeMap<TokenId, U256>,
+    /// The base coin amount of the outputs.
+    pub output_amount: u64,
+    /// The native token amounts of the outputs.
+    pub output_native_tokens: BTreeMap<TokenId, U256>,
+    /// The amounts the inputs require to be returned, by return address.
+    pub storage_deposit_returns: HashMap<Address, u64>,
+    /// The amounts of the basic outputs that only have an address unlock condition, by address.
+    pub simple_deposits: HashMap<Address, u64>,
+}
+
+/// Checks the part of the semantic validation that doesn't depend on the unlocks of a transaction.
+///
+/// That is that none of its inputs is time locked at `timestamp`, that the storage deposit returns are fulfilled, that
+/// the base coin amounts balance and that native tokens are only minted with their foundry as output. Returns the
+/// balances of the transaction.
+pub fn verify_balances<'a>(
+    inputs: impl IntoIterator<Item = (&'a OutputId, &'a Output)>,
+    outputs: &[Output],
+    timestamp: u32,
+) -> Result<Balances, BalanceError> {
+    let mut balances = Balances::default();
 
-        if unlock_conditions.is_time_locked(context.milestone_timestamp) {
-            return Ok(ConflictReason::TimelockNotExpired);
+    // Validation of inputs.
+    for (output_id, consumed_output) in inputs {
+        let unlock_conditions = consumed_output
+            .unlock_conditions()
+            .ok_or_else(|| BalanceError::UnsupportedOutputKind(consumed_output.kind()))?;
+
+        if unlock_conditions.is_time_locked(timestamp) {
+            return Err(BalanceError::TimelockNotExpired(*output_id));
         }
 
-        if !unlock_conditions.is_expired(context.milestone_timestamp) {
+        if !unlock_conditions.is_expired(timestamp) {
             if let Some(storage_deposit_return) = unlock_conditions.storage_deposit_return() {
-                let amount = context
+                let amount = balances
                     .storage_deposit_returns
                     .entry(*storage_deposit_return.return_address())
                     .or_default();
 
                 *amount = amount
                     .checked_add(storage_deposit_return.amount())
-                    .ok_or(Error::StorageDepositReturnOverflow)?;
+                    .ok_or(BalanceError::StorageDepositReturnOverflow)?;
             }
         }
 
-        context.input_amount = context
+        balances.input_amount = balances
             .input_amount
-            .checked_add(amount)
-            .ok_or(Error::ConsumedAmountOverflow)?;
+            .checked_add(consumed_output.amount())
+            .ok_or(BalanceError::ConsumedAmountOverflow)?;
 
-        for native_token in consumed_native_tokens.iter() {
-            let native_token_amount = context.input_native_tokens.entry(*native_token.token_id()).or_default();
+        for native_token in consumed_output.native_tokens().iter().flat_map(|n| n.iter()) {
+            let native_token_amount = balances
+                .input_native_tokens
+                .entry(*native_token.token_id())
+                .or_default();
 
             *native_token_amount = native_token_amount
                 .checked_add(native_token.amount())
-                .ok_or(Error::ConsumedNativeTokensAmountOverflow)?;
+                .ok_or_else(|| BalanceError::ConsumedNativeTokensAmountOverflow(*native_token.token_id()))?;
         }
     }
 
     // Validation of outputs.
-    for created_output in context.essence.outputs() {
-        let (amount, created_native_tokens, features) = match created_output {
+    for created_output in outputs {
+        match created_output {
             Output::Basic(output) => {
                 if let Some(address) = output.simple_deposit_address() {
-                    let amount = context.simple_deposits.entry(*address).or_default();
+                    let amount = balances.simple_deposits.entry(*address).or_default();
 
                     *amount = amount
                         .checked_add(output.amount())
-                        .ok_or(Error::CreatedAmountOverflow)?;
+                        .ok_or(BalanceError::CreatedAmountOverflow)?;
                 }
-
-                (output.amount(), output.native_tokens(), output.features())
-            }
-            Output::Alias(output) => (output.amount(), output.native_tokens(), output.features()),
-            Output::Foundry(output) => (output.amount(), output.native_tokens(), output.features()),
-            Output::Nft(output) => (output.amount(), output.native_tokens(), output.features()),
-            _ => return Err(Error::UnsupportedOutputKind(created_output.kind())),
-        };
-
-        if let Some(sender) = features.sender() {
-            if !context.unlocked_addresses.contains(sender.address()) {
-                return Ok(ConflictReason::UnverifiedSender);
             }
+            Output::Alias(_) | Output::Foundry(_) | Output::Nft(_) => {}
+            _ => return Err(BalanceError::UnsupportedOutputKind(created_output.kind())),
         }
 
-        context.output_amount = context
+        balances.output_amount = balances
             .output_amount
-            .checked_add(amount)
-            .ok_or(Error::CreatedAmountOverflow)?;
+            .checked_add(created_output.amount())
+            .ok_or(BalanceError::CreatedAmountOverflow)?;
 
-        for native_token in created_native_tokens.iter() {
-            let native_token_amount = context
+        for native_token in created_output.native_tokens().iter().flat_map(|n| n.iter()) {
+            let native_token_amount = balances
                 .output_native_tokens
                 .entry(*native_token.token_id())
                 .or_default();
 
             *native_token_amount = native_token_amount
                 .checked_add(native_token.amount())
-                .ok_or(Error::CreatedNativeTokensAmountOverflow)?;
+                .ok_or_else(|| BalanceError::CreatedNativeTokensAmountOverflow(*native_token.token_id()))?;
         }
     }
 
     // Validation of storage deposit returns.
-    for (return_address, return_amount) in context.storage_deposit_returns.iter() {
-        if let Some(deposit_amount) = context.simple_deposits.get(return_address) {
-            if deposit_amount < return_amount {
-                return Ok(ConflictReason::StorageDepositReturnUnfulfilled);
-            }
-        } else {
-            return Ok(ConflictReason::StorageDepositReturnUnfulfilled);
+    for (return_address, return_amount) in balances.storage_deposit_returns.iter() {
+        let deposit_amount = balances
+            .simple_deposits
+            .get(return_address)
+            .copied()
+            .unwrap_or_default();
+
+        if deposit_amount < *return_amount {
+            return Err(BalanceError::StorageDepositReturnUnfulfilled(*return_address));
         }
     }
 
     // Validation of amounts.
-    if context.input_amount != context.output_amount {
-        return Ok(ConflictReason::CreatedConsumedAmountMismatch);
+    if balances.input_amount != balances.output_amount {
+        return Err(BalanceError::AmountMismatch {
+            input: balances.input_amount,
+            output: balances.output_amount,
+        });
     }
 
-    let mut native_token_ids = HashSet::new();
+    // Validation of output native tokens, they can only be minted if their foundry is an output.
+    for (token_id, output_amount) in balances.output_native_tokens.iter() {
+        let input_amount = balances.input_native_tokens.get(token_id).copied().unwrap_or_default();
+        let foundry_id = ChainId::from(FoundryId::from(*token_id));
+
+        if *output_amount > input_amount && !outputs.iter().any(|output| output.chain_id() == Some(foundry_id)) {
+            return Err(BalanceError::NativeTokenAmountMismatch {
+                token_id: *token_id,
+                input: input_amount,
+                output: *output_amount,
+            });
+        }
+    }
 
-    // Validation of input native tokens.
-    for (token_id, _input_amount) in context.input_native_tokens.iter() {
-        native_token_ids.insert(token_id);
+    let native_token_count = balances
+        .input_native_tokens
+        .keys()
+        .chain(balances.output_native_tokens.keys())
+        .collect::<HashSet<_>>()
+        .len();
+
+    if native_token_count > NativeTokens::COUNT_MAX as usize {
+        return Err(BalanceError::NativeTokensCountExceeded(native_token_count));
     }
 
-    // Validation of output native tokens.
-    for (token_id, output_amount) in context.output_native_tokens.iter() {
-        let input_amount = context.input_native_tokens.get(token_id).copied().unwrap_or_default();
+    Ok(balances)
+}
 
-        if output_amount > &input_amount
-            && !context
-                .output_chains
-                .contains_key(&ChainId::from(FoundryId::from(*token_id)))
-        {
-            return Ok(ConflictReason::InvalidNativeTokens);
-        }
+///
+pub fn semantic_validation(
+    mut context: ValidationContext<'_>,
+    inputs: &[(&OutputId, &Output)],
+    unlocks: &Unlocks,
+) -> Result<ConflictReason, Error> {
+    // Validation of the inputs commitment.
+    if context.essence.inputs_commitment() != &context.inputs_commitment {
+        return Ok(ConflictReason::InputsCommitmentsMismatch);
+    }
+
+    // Validation of the unlocks.
+    for ((output_id, consumed_output), unlock) in inputs.iter().zip(unlocks.iter()) {
+        let conflict = match consumed_output {
+            Output::Basic(output) => output.unlock(output_id, unlock, inputs, &mut context),
+            Output::Alias(output) => output.unlock(output_id, unlock, inputs, &mut context),
+            Output::Foundry(output) => output.unlock(output_id, unlock, inputs, &mut context),
+            Output::Nft(output) => output.unlock(output_id, unlock, inputs, &mut context),
+            _ => return Err(Error::UnsupportedOutputKind(consumed_output.kind())),
+        };
 
-        native_token_ids.insert(token_id);
+        if let Err(conflict) = conflict {
+            return Ok(conflict);
+        }
     }
 
-    if native_token_ids.len() > NativeTokens::COUNT_MAX as usize {
-        return Ok(ConflictReason::InvalidNativeTokens);
+    // Validation of the senders, they need to be unlocked by the inputs.
+    for created_output in context.essence.outputs() {
+        if let Some(sender) = created_output.features().and_then(|features| features.sender()) {
+            if !context.unlocked_addresses.contains(sender.address()) {
+                return Ok(ConflictReason::UnverifiedSender);
+            }
+        }
     }
 
+    let balances = match verify_balances(
+        inputs.iter().copied(),
+        context.essence.outputs(),
+        context.milestone_timestamp,
+    ) {
+        Ok(balances) => balances,
+        Err(error) => return error.into_conflict_reason(),
+    };
+
+    context.input_amount = balances.input_amount;
+    context.input_native_tokens = balances.input_native_tokens;
+    context.output_amount = balances.output_amount;
+    context.output_native_tokens = balances.output_native_tokens;
+    context.storage_deposit_returns = balances.storage_deposit_returns;
+    context.simple_deposits = balances.simple_deposits;
+
     // Validation of state transitions and destructions.
     for (chain_id, current_state) in context.input_chains.iter() {
         if Output::verify_state_transition(
@@ -24,20 +24,26 @@ use tokio::sync::{Mutex, RwLock};
 
 #[cfg(feature = "participation")]
 pub use self::operations::participation::{AccountParticipationOverview, ParticipationEventWithNodes};
-use self::types::{
-    address::{AccountAddress, AddressWithUnspentOutputs},
-    Balance, OutputData, Transaction, TransactionDto,
+use self::{
+    operations::helpers::time::can_output_be_unlocked_now,
+    types::{
+        address::{AccountAddress, AddressWithUnspentOutputs},
+        Balance, OutputData, Transaction, TransactionDto,
+    },
 };
 pub use self::{
     operations::{
         output_claiming::OutputsToClaim,
-        output_consolidation::ConsolidationParams,
+        output_consolidation::{ConsolidationParams, NativeTokenConsolidationStrategy, PreparedConsolidation},
+        output_splitting::SplitOutputTransaction,
+        retry::{RetryAttempt, RetryTransactionReport},
         syncing::{
-            options::{AccountSyncOptions, AliasSyncOptions, NftSyncOptions},
+            options::{AccountSyncOptions, AliasSyncOptions, AutoConsolidateOptions, NftSyncOptions},
             SyncOptions,
         },
         transaction::{
             high_level::{
+                burning_melting::{BurnEstimate, BurnHandle, PreparedNativeTokensBurn},
                 create_alias::CreateAliasParams,
                 minting::{
                     create_native_token::{
@@ -46,6 +52,7 @@ pub use self::{
                     },
                     mint_nfts::MintNftParams,
                 },
+                send::PreparedSendMax,
             },
             prepare_output::{Assets, Features, OutputParams, ReturnStrategy, StorageDeposit, Unlocks},
             RemainderValueStrategy, TransactionOptions, TransactionOptionsDto,
@@ -62,7 +69,11 @@ use crate::{
     types::{
         api::core::response::OutputWithMetadataResponse,
         block::{
-            output::{dto::FoundryOutputDto, AliasId, FoundryId, FoundryOutput, NftId, Output, OutputId, TokenId},
+            address::Address,
+            output::{
+                dto::FoundryOutputDto, AliasId, FoundryId, FoundryOutput, NftId, Output, OutputId, OutputMetadata,
+                OutputWithMetadata, TokenId,
+            },
             payload::{
                 transaction::{TransactionEssence, TransactionId},
                 TransactionPayload,
@@ -116,6 +127,8 @@ pub struct AccountDetails {
     // outputs used in transactions should be locked here so they don't get used again, which would result in a
     // conflicting transaction
     pub(crate) locked_outputs: HashSet<OutputId>,
+    /// Unspent outputs that are frozen, so they aren't used as input for transactions
+    pub(crate) frozen_outputs: HashSet<OutputId>,
     /// Unspent outputs
     // have unspent outputs in a separated hashmap so we don't need to iterate over all outputs we have
     unspent_outputs: HashMap<OutputId, OutputData>,
@@ -137,6 +150,17 @@ pub struct AccountDetails {
     native_token_foundries: HashMap<FoundryId, FoundryOutput>,
 }
 
+/// A consistent view of an account at a point in time, see
+/// [`Account::snapshot()`](crate::wallet::Account::snapshot).
+#[derive(Clone, Debug, Eq, PartialEq, Getters)]
+#[getset(get = "pub")]
+pub struct AccountSnapshot {
+    /// The account details, including outputs and transactions.
+    details: AccountDetails,
+    /// The balance computed from `details`.
+    balance: Balance,
+}
+
 /// A thread guard over an account, so we can lock the account during operations.
 #[derive(Debug)]
 pub struct Account<S: SecretManage = SecretManager> {
@@ -165,7 +189,9 @@ pub struct AccountInner {
     // mutex to prevent multiple sync calls at the same or almost the same time, the u128 is a timestamp
     // if the last synced time was < `MIN_SYNC_INTERVAL` second ago, we don't sync, but only calculate the balance
     // again, because sending transactions can change that
-    pub(crate) last_synced: Mutex<u128>,
+    // the options are the ones of the last sync, so a sync that waited for another one with the same options can use
+    // its result instead of syncing again
+    pub(crate) last_synced: Mutex<(u128, Option<SyncOptions>)>,
     pub(crate) default_sync_options: Mutex<SyncOptions>,
 }
 
@@ -230,6 +256,58 @@ where
         Ok(output_response.output().to_owned())
     }
 
+    /// Get the [`OutputWithMetadata`] of an output. The account state is checked first and only if the output isn't
+    /// known to the account and `query_node` is set, it's requested from the node. Note that the metadata of outputs
+    /// from the account state is only as recent as the last sync. Returns `None` if the output wasn't found.
+    pub async fn get_output_with_metadata(
+        &self,
+        output_id: &OutputId,
+        query_node: bool,
+    ) -> Result<Option<OutputWithMetadata>> {
+        if let Some(output_data) = self.get_output(output_id).await {
+            return Ok(Some(OutputWithMetadata::new(output_data.output, output_data.metadata)));
+        }
+
+        if !query_node {
+            return Ok(None);
+        }
+
+        match self.client().get_output(output_id).await {
+            Ok(output) => Ok(Some(output)),
+            Err(crate::client::Error::Node(crate::client::node_api::error::Error::NotFound(_))) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Get the [`OutputMetadata`] of an output, see
+    /// [`Account::get_output_with_metadata()`](crate::wallet::Account::get_output_with_metadata).
+    pub async fn get_output_metadata(&self, output_id: &OutputId, query_node: bool) -> Result<Option<OutputMetadata>> {
+        if let Some(output_data) = self.get_output(output_id).await {
+            return Ok(Some(output_data.metadata));
+        }
+
+        if !query_node {
+            return Ok(None);
+        }
+
+        match self.client().get_output_metadata(output_id).await {
+            Ok(metadata) => Ok(Some(metadata)),
+            Err(crate::client::Error::Node(crate::client::node_api::error::Error::NotFound(_))) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Returns the unspent outputs that can be unlocked at the current time, evaluating their timelock and expiration
+    /// unlock conditions. Outputs owned by alias or NFT outputs of the account are included, outputs used by pending
+    /// transactions or frozen with [`Account::freeze_output()`](crate::wallet::Account::freeze_output) aren't. Outputs
+    /// with a storage deposit return unlock condition are included, but the deposit needs to be returned in the same
+    /// transaction.
+    pub async fn outputs_unlockable_now(&self) -> Result<Vec<OutputData>> {
+        let current_time = self.client().get_time_checked().await?;
+
+        self.details().await.outputs_unlockable_at(current_time)
+    }
+
     /// Save the account to the database, accepts the updated_account as option so we don't need to drop it before
     /// saving
     #[cfg(feature = "storage")]
@@ -252,6 +330,20 @@ where
         Ok(())
     }
 
+    /// Save the account to the database according to the configured
+    /// [`StorageWritePolicy`](crate::wallet::storage::StorageWritePolicy). Must only be used for changes that can be
+    /// recovered by syncing again.
+    #[cfg(feature = "storage")]
+    pub(crate) async fn save_batched(&self, updated_account: &AccountDetails) -> Result<()> {
+        log::debug!("[save_batched] saving account to database");
+        self.wallet
+            .storage_manager
+            .write()
+            .await
+            .save_account_batched(updated_account)
+            .await
+    }
+
     #[cfg(feature = "events")]
     pub(crate) async fn emit(&self, account_index: u32, wallet_event: super::events::types::WalletEvent) {
         self.wallet.emit(account_index, wallet_event).await
@@ -428,6 +520,11 @@ impl AccountInner {
         self.details().await.transactions.values().cloned().collect()
     }
 
+    /// Returns the IDs of the outputs frozen with [`Account::freeze_output()`](crate::wallet::Account::freeze_output)
+    pub async fn frozen_outputs(&self) -> HashSet<OutputId> {
+        self.details().await.frozen_outputs.clone()
+    }
+
     /// Returns all pending transactions of the account
     pub async fn pending_transactions(&self) -> Vec<Transaction> {
         let mut transactions = Vec::new();
@@ -450,6 +547,38 @@ impl AccountDetails {
         all_addresses.extend(self.internal_addresses().clone());
         all_addresses.to_vec()
     }
+
+    /// Returns the unspent outputs that can be unlocked at `current_time`, see
+    /// [`Account::outputs_unlockable_now()`](crate::wallet::Account::outputs_unlockable_now).
+    pub(crate) fn outputs_unlockable_at(&self, current_time: u32) -> Result<Vec<OutputData>> {
+        let alias_and_nft_addresses = self
+            .unspent_outputs
+            .values()
+            .filter_map(|output_data| match &output_data.output {
+                Output::Alias(alias) => Some(Address::Alias(alias.alias_address(&output_data.output_id))),
+                Output::Nft(nft) => Some(Address::Nft(nft.nft_address(&output_data.output_id))),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut outputs = Vec::new();
+        for (output_id, output_data) in &self.unspent_outputs {
+            if self.locked_outputs.contains(output_id) || self.frozen_outputs.contains(output_id) {
+                continue;
+            }
+            if can_output_be_unlocked_now(
+                &self.addresses_with_unspent_outputs,
+                &alias_and_nft_addresses,
+                output_data,
+                current_time,
+                None,
+            )? {
+                outputs.push(output_data.clone());
+            }
+        }
+
+        Ok(outputs)
+    }
 }
 
 pub(crate) fn build_transaction_from_payload_and_inputs(
@@ -494,6 +623,9 @@ pub struct AccountDetailsDto {
     pub outputs: HashMap<OutputId, OutputDataDto>,
     /// Unspent outputs that are currently used as input for transactions
     pub locked_outputs: HashSet<OutputId>,
+    /// Unspent outputs that are frozen, so they aren't used as input for transactions
+    #[serde(default)]
+    pub frozen_outputs: HashSet<OutputId>,
     /// Unspent outputs
     pub unspent_outputs: HashMap<OutputId, OutputDataDto>,
     /// Sent transactions
@@ -528,6 +660,7 @@ impl TryFromDto for AccountDetails {
                 .map(|(id, o)| Ok((id, OutputData::try_from_dto_with_params(o, &params)?)))
                 .collect::<crate::wallet::Result<_>>()?,
             locked_outputs: dto.locked_outputs,
+            frozen_outputs: dto.frozen_outputs,
             unspent_outputs: dto
                 .unspent_outputs
                 .into_iter()
@@ -569,6 +702,7 @@ impl From<&AccountDetails> for AccountDetailsDto {
                 .map(|(id, output)| (*id, OutputDataDto::from(output)))
                 .collect(),
             locked_outputs: value.locked_outputs().clone(),
+            frozen_outputs: value.frozen_outputs().clone(),
             unspent_outputs: value
                 .unspent_outputs()
                 .iter()
@@ -604,11 +738,15 @@ mod test {
     use crate::types::block::{
         address::{Address, Ed25519Address},
         input::{Input, UtxoInput},
-        output::{AddressUnlockCondition, BasicOutput, InputsCommitment, RentStructure},
+        output::{
+            unlock_condition::{ExpirationUnlockCondition, TimelockUnlockCondition, UnlockCondition},
+            AddressUnlockCondition, BasicOutput, BasicOutputBuilder, InputsCommitment, RentStructure,
+        },
         payload::transaction::RegularTransactionEssence,
         protocol::ProtocolParameters,
         signature::{Ed25519Signature, Signature},
         unlock::{ReferenceUnlock, SignatureUnlock, Unlock, Unlocks},
+        BlockId,
     };
 
     #[test]
@@ -688,6 +826,7 @@ mod test {
             addresses_with_unspent_outputs: Vec::new(),
             outputs: HashMap::new(),
             locked_outputs: HashSet::new(),
+            frozen_outputs: HashSet::new(),
             unspent_outputs: HashMap::new(),
             transactions: HashMap::new(),
             pending_transactions: HashSet::new(),
@@ -707,12 +846,90 @@ mod test {
         assert_eq!(account, deser_account);
     }
 
+    #[test]
+    fn outputs_unlockable_at() {
+        const CURRENT_TIME: u32 = 1_700_000_000;
+        const TOKEN_SUPPLY: u64 = 1_813_620_509_061_365;
+
+        let mut account_details = AccountDetails::mock();
+        let bech32_address = account_details.public_addresses[0].address;
+        let address = bech32_address.into_inner();
+        let other_address = Address::from(Ed25519Address::new([1; 32]));
+        account_details.addresses_with_unspent_outputs = vec![AddressWithUnspentOutputs {
+            address: bech32_address,
+            key_index: 0,
+            internal: false,
+            output_ids: Vec::new(),
+        }];
+
+        let unlock_conditions: [Vec<UnlockCondition>; 7] = [
+            vec![AddressUnlockCondition::new(address).into()],
+            vec![
+                AddressUnlockCondition::new(address).into(),
+                TimelockUnlockCondition::new(CURRENT_TIME + 100).unwrap().into(),
+            ],
+            vec![
+                AddressUnlockCondition::new(address).into(),
+                TimelockUnlockCondition::new(CURRENT_TIME - 100).unwrap().into(),
+            ],
+            // Expired, so it can only be unlocked by the other address
+            vec![
+                AddressUnlockCondition::new(address).into(),
+                ExpirationUnlockCondition::new(other_address, CURRENT_TIME - 100)
+                    .unwrap()
+                    .into(),
+            ],
+            // Expired, so it returned to the account
+            vec![
+                AddressUnlockCondition::new(other_address).into(),
+                ExpirationUnlockCondition::new(address, CURRENT_TIME - 100)
+                    .unwrap()
+                    .into(),
+            ],
+            // Frozen
+            vec![AddressUnlockCondition::new(address).into()],
+            // Used by a pending transaction
+            vec![AddressUnlockCondition::new(address).into()],
+        ];
+        let output_ids = (0..unlock_conditions.len() as u16)
+            .map(|index| OutputId::new(TransactionId::new([0; 32]), index).unwrap())
+            .collect::<Vec<_>>();
+        for (output_id, unlock_conditions) in output_ids.iter().zip(unlock_conditions) {
+            let output = BasicOutputBuilder::new_with_amount(1_000_000)
+                .with_unlock_conditions(unlock_conditions)
+                .finish_output(TOKEN_SUPPLY)
+                .unwrap();
+            account_details.unspent_outputs.insert(
+                *output_id,
+                OutputData {
+                    output_id: *output_id,
+                    metadata: OutputMetadata::new(BlockId::new([0; 32]), *output_id, false, None, None, None, 0, 0, 0),
+                    output,
+                    is_spent: false,
+                    address,
+                    network_id: 0,
+                    remainder: false,
+                    chain: None,
+                },
+            );
+        }
+        account_details.frozen_outputs.insert(output_ids[5]);
+        account_details.locked_outputs.insert(output_ids[6]);
+
+        let unlockable = account_details
+            .outputs_unlockable_at(CURRENT_TIME)
+            .unwrap()
+            .into_iter()
+            .map(|output_data| output_data.output_id)
+            .collect::<HashSet<_>>();
+        assert_eq!(unlockable, HashSet::from([output_ids[0], output_ids[2], output_ids[4]]));
+    }
+
     impl AccountDetails {
         /// Returns a mock of this type with the following values:
         /// index: 0, coin_type: 4218, alias: "Alice", public_addresses: contains a single public account address
         /// (rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy), all other fields are set to their Rust
         /// defaults.
-        #[cfg(feature = "storage")]
         pub(crate) fn mock() -> Self {
             Self {
                 index: 0,
@@ -731,6 +948,7 @@ mod test {
                 addresses_with_unspent_outputs: Vec::new(),
                 outputs: HashMap::new(),
                 locked_outputs: HashSet::new(),
+                frozen_outputs: HashSet::new(),
                 unspent_outputs: HashMap::new(),
                 transactions: HashMap::new(),
                 pending_transactions: HashSet::new(),
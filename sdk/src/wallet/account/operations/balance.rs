@@ -6,7 +6,7 @@ use primitive_types::U256;
 use crate::{
     client::secret::SecretManage,
     types::block::{
-        address::Bech32Address,
+        address::{Address, Bech32Address},
         output::{unlock_condition::UnlockCondition, FoundryId, NativeTokensBuilder, Output, Rent},
         ConvertTo,
     },
@@ -14,7 +14,7 @@ use crate::{
         account::{
             operations::helpers::time::can_output_be_unlocked_forever_from_now_on,
             types::{AddressWithUnspentOutputs, Balance, NativeTokensBalance},
-            Account, AccountDetails, OutputsToClaim,
+            Account, AccountDetails, AccountSnapshot, OutputsToClaim,
         },
         Error, Result,
     },
@@ -34,6 +34,28 @@ where
             .await
     }
 
+    /// Get a consistent snapshot of the account details and the balance computed from them, so a transaction can be
+    /// computed against a stable state.
+    ///
+    /// The snapshot is a copy and doesn't change afterwards. Taking it waits for state updates that are in progress,
+    /// like a sync writing its results, but syncing and sending consist of multiple steps, so the snapshot can reflect
+    /// the state between two of them. Call [`Account::sync()`] before taking a snapshot if an up-to-date view is
+    /// needed.
+    pub async fn snapshot(&self) -> Result<AccountSnapshot> {
+        log::debug!("[BALANCE] snapshot");
+
+        let account_details = self.details().await;
+
+        let balance = self
+            .balance_inner(account_details.addresses_with_unspent_outputs.iter(), &account_details)
+            .await?;
+
+        Ok(AccountSnapshot {
+            details: account_details.clone(),
+            balance,
+        })
+    }
+
     /// Get the balance of the given addresses.
     pub async fn addresses_balance(&self, addresses: Vec<impl ConvertTo<Bech32Address>>) -> Result<Balance> {
         log::debug!("[BALANCE] addresses_balance");
@@ -56,6 +78,121 @@ where
             .await
     }
 
+    /// Get the balance the account had at the given unix timestamp in seconds, computed from the outputs that were
+    /// booked at or before it and not spent yet.
+    ///
+    /// The account keeps its spent outputs, but only outputs it synced while they were unspent are known, so accounts
+    /// that were restored from a mnemonic only know their history from the first sync on. The time an output was spent
+    /// is taken from the node or, if the node pruned it already, from the transaction of the account that spent it.
+    /// Storage deposits, voting power and locked outputs aren't taken into account, so `available` equals `total`.
+    pub async fn balance_at(&self, timestamp: u32) -> Result<Balance> {
+        log::debug!("[BALANCE] balance_at {timestamp}");
+
+        let network_id = self.client().get_network_id().await?;
+        let account_details = self.details().await;
+        let account_addresses = account_details.addresses();
+
+        let mut existing_outputs = Vec::new();
+        for output_data in account_details.outputs.values() {
+            if output_data.network_id != network_id || output_data.metadata.milestone_timestamp_booked() > timestamp {
+                continue;
+            }
+            if output_data.is_spent {
+                let spent_timestamp = output_data
+                    .metadata
+                    .milestone_timestamp_spent()
+                    .or_else(|| {
+                        account_details
+                            .transactions
+                            .values()
+                            .find(|transaction| {
+                                transaction
+                                    .inputs
+                                    .iter()
+                                    .any(|input| input.metadata.output_id() == &output_data.output_id)
+                            })
+                            .map(|transaction| (transaction.timestamp / 1000) as u32)
+                    })
+                    .ok_or(Error::UnknownSpentTimestamp(output_data.output_id))?;
+                if spent_timestamp <= timestamp {
+                    continue;
+                }
+            }
+            existing_outputs.push(output_data);
+        }
+
+        let alias_and_nft_addresses = existing_outputs
+            .iter()
+            .filter_map(|output_data| match &output_data.output {
+                Output::Alias(alias) => Some(Address::Alias(alias.alias_address(&output_data.output_id))),
+                Output::Nft(nft) => Some(Address::Nft(nft.nft_address(&output_data.output_id))),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut balance = Balance::default();
+        let mut total_native_tokens = NativeTokensBuilder::default();
+
+        for output_data in existing_outputs {
+            let output = &output_data.output;
+            let (required_address, _) =
+                output.required_and_unlocked_address(timestamp, &output_data.output_id, None)?;
+            if !account_addresses.iter().any(|a| a.address.inner == required_address)
+                && !alias_and_nft_addresses.contains(&required_address)
+            {
+                continue;
+            }
+
+            // The storage deposit needs to be returned, unless it's returned to the account itself
+            let amount = output
+                .unlock_conditions()
+                .and_then(|u| u.storage_deposit_return())
+                .map_or_else(
+                    || output.amount(),
+                    |sdr| {
+                        if account_addresses
+                            .iter()
+                            .any(|a| a.address.inner == *sdr.return_address())
+                        {
+                            output.amount()
+                        } else {
+                            output.amount() - sdr.amount()
+                        }
+                    },
+                );
+            balance.base_coin.total += amount;
+
+            if let Some(native_tokens) = output.native_tokens() {
+                total_native_tokens.add_native_tokens(native_tokens.clone())?;
+            }
+
+            match output {
+                Output::Alias(output) => balance.aliases.push(output.alias_id_non_null(&output_data.output_id)),
+                Output::Foundry(output) => balance.foundries.push(output.id()),
+                Output::Nft(output) => balance.nfts.push(output.nft_id_non_null(&output_data.output_id)),
+                _ => {}
+            }
+        }
+        balance.base_coin.available = balance.base_coin.total;
+
+        for native_token in total_native_tokens.finish_set()? {
+            let metadata = account_details
+                .native_token_foundries
+                .get(&FoundryId::from(*native_token.token_id()))
+                .and_then(|foundry| foundry.immutable_features().metadata())
+                .cloned();
+
+            balance.native_tokens.push(NativeTokensBalance {
+                token_id: *native_token.token_id(),
+                total: native_token.amount(),
+                available: native_token.amount(),
+                metadata,
+            })
+        }
+
+        Ok(balance)
+    }
+
     async fn balance_inner(
         &self,
         addresses_with_unspent_outputs: impl Iterator<Item = &AddressWithUnspentOutputs> + Send,
@@ -287,13 +424,14 @@ where
         total_rent_amount: u64,
         total_native_tokens: NativeTokensBuilder,
     ) -> Result<Balance> {
-        // for `available` get locked_outputs, sum outputs amount and subtract from total_amount
+        // for `available` get locked_outputs and frozen_outputs, sum outputs amount and subtract from total_amount
         log::debug!("[BALANCE] locked outputs: {:#?}", account_details.locked_outputs);
+        log::debug!("[BALANCE] frozen outputs: {:#?}", account_details.frozen_outputs);
 
         let mut locked_amount = 0;
         let mut locked_native_tokens = NativeTokensBuilder::default();
 
-        for locked_output in &account_details.locked_outputs {
+        for locked_output in account_details.locked_outputs.union(&account_details.frozen_outputs) {
             // Skip potentially_locked_outputs, as their amounts aren't added to the balance
             if balance.potentially_locked_outputs.contains_key(locked_output) {
                 continue;
@@ -15,6 +15,8 @@ pub(crate) mod output_claiming;
 pub(crate) mod output_consolidation;
 /// The module to find additional addresses with unspent outputs
 pub(crate) mod output_finder;
+/// The module for splitting an output
+pub(crate) mod output_splitting;
 /// The module for participation
 #[cfg(feature = "participation")]
 pub(crate) mod participation;
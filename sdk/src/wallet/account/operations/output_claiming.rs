@@ -53,8 +53,11 @@ impl AccountDetails {
             .iter()
             .filter(|(_, o)| o.output.is_basic() || o.output.is_nft())
         {
-            // Don't use outputs that are locked for other transactions
-            if !self.locked_outputs.contains(output_id) && self.outputs.contains_key(output_id) {
+            // Don't use outputs that are locked for other transactions or frozen
+            if !self.locked_outputs.contains(output_id)
+                && !self.frozen_outputs.contains(output_id)
+                && self.outputs.contains_key(output_id)
+            {
                 if let Some(unlock_conditions) = output_data.output.unlock_conditions() {
                     // If there is a single [UnlockCondition], then it's an
                     // [AddressUnlockCondition] and we own it already without
@@ -159,8 +162,10 @@ where
                     continue;
                 }
             }
-            // Don't use outputs that are locked for other transactions
-            if !account_details.locked_outputs.contains(output_id) {
+            // Don't use outputs that are locked for other transactions or frozen
+            if !account_details.locked_outputs.contains(output_id)
+                && !account_details.frozen_outputs.contains(output_id)
+            {
                 if let Some(output) = account_details.outputs.get(output_id) {
                     if let Output::Basic(basic_output) = &output.output {
                         if basic_output.unlock_conditions().len() == 1 {
@@ -234,7 +239,9 @@ where
         let mut outputs_to_claim = Vec::new();
         for output_id in output_ids_to_claim {
             if let Some(output_data) = account_details.unspent_outputs.get(&output_id) {
-                if !account_details.locked_outputs.contains(&output_id) {
+                if !account_details.locked_outputs.contains(&output_id)
+                    && !account_details.frozen_outputs.contains(&output_id)
+                {
                     outputs_to_claim.push(output_data.clone());
                 }
             }
@@ -1,6 +1,8 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::{BTreeMap, BTreeSet};
+
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "ledger_nano")]
@@ -12,6 +14,7 @@ use crate::{
         input::INPUT_COUNT_MAX,
         output::{
             unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeTokens, NativeTokensBuilder, Output,
+            OutputId, TokenId,
         },
     },
 };
@@ -46,6 +49,42 @@ pub struct ConsolidationParams {
     output_threshold: Option<usize>,
     /// Address to which the consolidated output should be sent.
     target_address: Option<Bech32Address>,
+    /// How outputs holding native tokens are consolidated.
+    #[serde(default)]
+    native_token_strategy: NativeTokenConsolidationStrategy,
+}
+
+/// How outputs holding native tokens are consolidated, see [`ConsolidationParams::with_native_token_strategy()`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum NativeTokenConsolidationStrategy {
+    /// Merges all native tokens into a single consolidated output, outputs which would exceed the max native token
+    /// count of an output aren't consolidated.
+    #[default]
+    Merge,
+    /// Creates a consolidated output per set of native token IDs. Outputs without native tokens form a set as well,
+    /// outputs whose set isn't shared by another output aren't consolidated. Every set needs to reach the output
+    /// threshold on its own, unless it's forced.
+    GroupByTokenSet,
+    /// Doesn't consolidate outputs holding native tokens.
+    Skip,
+}
+
+/// A prepared consolidation, see [`Account::prepare_consolidate_outputs_with_report()`].
+#[derive(Clone, Debug)]
+pub struct PreparedConsolidation {
+    /// The prepared consolidation transaction.
+    pub prepared_transaction_data: PreparedTransactionData,
+    /// The number of outputs which weren't consolidated because of their native tokens, including the outputs of
+    /// native token sets below the output threshold.
+    pub skipped_outputs: usize,
+}
+
+// Outputs consolidated into a single output.
+#[derive(Debug, Default)]
+struct ConsolidationGroup {
+    inputs: Vec<OutputId>,
+    amount: u64,
+    native_tokens: NativeTokensBuilder,
 }
 
 impl ConsolidationParams {
@@ -67,6 +106,11 @@ impl ConsolidationParams {
         self.target_address = target_address.into();
         self
     }
+
+    pub fn with_native_token_strategy(mut self, native_token_strategy: NativeTokenConsolidationStrategy) -> Self {
+        self.native_token_strategy = native_token_strategy;
+        self
+    }
 }
 
 impl<S: 'static + SecretManage> Account<S>
@@ -122,6 +166,17 @@ where
     /// Prepares the transaction for
     /// [Account::consolidate_outputs()](crate::wallet::Account::consolidate_outputs).
     pub async fn prepare_consolidate_outputs(&self, params: ConsolidationParams) -> Result<PreparedTransactionData> {
+        self.prepare_consolidate_outputs_with_report(params)
+            .await
+            .map(|prepared_consolidation| prepared_consolidation.prepared_transaction_data)
+    }
+
+    /// Like [`Account::prepare_consolidate_outputs()`], but also reports how many outputs weren't consolidated because
+    /// of their native tokens, see [`NativeTokenConsolidationStrategy`].
+    pub async fn prepare_consolidate_outputs_with_report(
+        &self,
+        params: ConsolidationParams,
+    ) -> Result<PreparedConsolidation> {
         log::debug!("[OUTPUT_CONSOLIDATION] prepare consolidating outputs if needed");
         #[cfg(feature = "participation")]
         let voting_output = self.get_voting_output().await?;
@@ -139,7 +194,8 @@ where
                     continue;
                 }
             }
-            let is_locked_output = account_details.locked_outputs.contains(output_id);
+            let is_locked_output = account_details.locked_outputs.contains(output_id)
+                || account_details.frozen_outputs.contains(output_id);
             let should_consolidate_output =
                 self.should_consolidate_output(output_data, current_time, account_addresses)?;
             if !is_locked_output && should_consolidate_output {
@@ -229,39 +285,255 @@ where
         #[cfg(not(feature = "ledger_nano"))]
         let max_inputs = INPUT_COUNT_MAX;
 
-        let mut total_amount = 0;
+        let target_address = params
+            .target_address
+            .map(|bech32| bech32.into_inner())
+            .unwrap_or(outputs_to_consolidate[0].address);
+
+        let (groups, skipped_outputs) = group_outputs(
+            outputs_to_consolidate.iter().take(max_inputs.into()),
+            params.native_token_strategy,
+            params.force,
+            output_threshold,
+        )?;
+
+        if groups.is_empty() {
+            log::debug!(
+                "[OUTPUT_CONSOLIDATION] no consolidation possible, skipped_outputs: {}, output_threshold: {}",
+                skipped_outputs,
+                output_threshold
+            );
+            return Err(crate::wallet::Error::NoOutputsToConsolidate {
+                available_outputs: outputs_to_consolidate.len(),
+                consolidation_threshold: output_threshold,
+            });
+        }
+
         let mut custom_inputs = Vec::with_capacity(max_inputs.into());
-        let mut total_native_tokens = NativeTokensBuilder::new();
+        let mut consolidation_outputs = Vec::with_capacity(groups.len());
+
+        for group in groups {
+            custom_inputs.extend(group.inputs);
+            consolidation_outputs.push(
+                BasicOutputBuilder::new_with_amount(group.amount)
+                    .add_unlock_condition(AddressUnlockCondition::new(target_address))
+                    .with_native_tokens(group.native_tokens.finish()?)
+                    .finish_output(token_supply)?,
+            );
+        }
+
+        let options = Some(TransactionOptions {
+            custom_inputs: Some(custom_inputs),
+            ..Default::default()
+        });
+
+        Ok(PreparedConsolidation {
+            prepared_transaction_data: self.prepare_transaction(consolidation_outputs, options).await?,
+            skipped_outputs,
+        })
+    }
+}
+
+// Groups the outputs to consolidate according to the native token strategy. Groups with fewer outputs than the
+// threshold aren't consolidated, unless `force` is set. Returns the groups and the number of outputs which aren't
+// consolidated.
+fn group_outputs<'a>(
+    outputs: impl IntoIterator<Item = &'a OutputData>,
+    native_token_strategy: NativeTokenConsolidationStrategy,
+    force: bool,
+    output_threshold: usize,
+) -> Result<(Vec<ConsolidationGroup>, usize)> {
+    let mut output_count = 0;
+    // Outputs to consolidate together, keyed by their native token IDs if grouped by them
+    let mut outputs_by_token_ids = BTreeMap::<BTreeSet<TokenId>, Vec<&OutputData>>::new();
+
+    for output_data in outputs {
+        output_count += 1;
+        let token_ids: BTreeSet<TokenId> = output_data
+            .output
+            .native_tokens()
+            .map(|native_tokens| {
+                native_tokens
+                    .iter()
+                    .map(|native_token| *native_token.token_id())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let key = match native_token_strategy {
+            NativeTokenConsolidationStrategy::Merge => BTreeSet::new(),
+            NativeTokenConsolidationStrategy::GroupByTokenSet => token_ids,
+            NativeTokenConsolidationStrategy::Skip => {
+                if !token_ids.is_empty() {
+                    continue;
+                }
+                token_ids
+            }
+        };
+        outputs_by_token_ids.entry(key).or_default().push(output_data);
+    }
 
-        for output_data in outputs_to_consolidate.iter().take(max_inputs.into()) {
+    let mut groups = Vec::new();
+
+    for (token_ids, outputs) in outputs_by_token_ids {
+        let mut group = ConsolidationGroup::default();
+
+        for output_data in outputs {
             if let Some(native_tokens) = output_data.output.native_tokens() {
+                let new_native_token_count = get_new_native_token_count(&group.native_tokens, native_tokens)?;
                 // Skip output if the max native tokens count would be exceeded
-                if get_new_native_token_count(&total_native_tokens, native_tokens)? > NativeTokens::COUNT_MAX.into() {
+                if new_native_token_count > NativeTokens::COUNT_MAX.into() {
                     log::debug!("[OUTPUT_CONSOLIDATION] skipping output to not exceed the max native tokens count");
                     continue;
                 }
-                total_native_tokens.add_native_tokens(native_tokens.clone())?;
+                group.native_tokens.add_native_tokens(native_tokens.clone())?;
             };
-            total_amount += output_data.output.amount();
+            group.amount += output_data.output.amount();
+            group.inputs.push(output_data.output_id);
+        }
 
-            custom_inputs.push(output_data.output_id);
+        // There is nothing to merge a single output with native tokens with
+        let min_inputs = if token_ids.is_empty() { 1 } else { 2 };
+        let threshold = if force {
+            min_inputs
+        } else {
+            output_threshold.max(min_inputs)
+        };
+        if group.inputs.len() >= threshold {
+            groups.push(group);
         }
+    }
 
-        let consolidation_output = [BasicOutputBuilder::new_with_amount(total_amount)
-            .add_unlock_condition(AddressUnlockCondition::new(
-                params
-                    .target_address
-                    .map(|bech32| bech32.into_inner())
-                    .unwrap_or(outputs_to_consolidate[0].address),
-            ))
-            .with_native_tokens(total_native_tokens.finish()?)
-            .finish_output(token_supply)?];
+    let consolidated_outputs = groups.iter().map(|group| group.inputs.len()).sum::<usize>();
 
-        let options = Some(TransactionOptions {
-            custom_inputs: Some(custom_inputs),
-            ..Default::default()
-        });
+    Ok((groups, output_count - consolidated_outputs))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::types::block::{
+        address::{Address, Ed25519Address},
+        output::{NativeToken, OutputMetadata},
+        payload::transaction::TransactionId,
+        BlockId,
+    };
+
+    const TOKEN_SUPPLY: u64 = 1_813_620_509_061_365;
+
+    fn output_data(index: u16, token_ids: impl IntoIterator<Item = u8>) -> OutputData {
+        let output_id = OutputId::new(TransactionId::new([0; 32]), index).unwrap();
+        let address = Address::from(Ed25519Address::new([0; 32]));
+        let output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .with_native_tokens(
+                token_ids
+                    .into_iter()
+                    .map(|token_id| NativeToken::new(TokenId::new([token_id; 38]), 10).unwrap()),
+            )
+            .finish_output(TOKEN_SUPPLY)
+            .unwrap();
+
+        OutputData {
+            output_id,
+            metadata: OutputMetadata::new(BlockId::new([0; 32]), output_id, false, None, None, None, 0, 0, 0),
+            output,
+            is_spent: false,
+            address,
+            network_id: 0,
+            remainder: false,
+            chain: None,
+        }
+    }
+
+    fn group_inputs(groups: &[ConsolidationGroup]) -> Vec<Vec<u16>> {
+        groups
+            .iter()
+            .map(|group| group.inputs.iter().map(|output_id| output_id.index()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn group_outputs_merge() {
+        let outputs = [output_data(0, []), output_data(1, [1]), output_data(2, [1, 2])];
+
+        let (groups, skipped_outputs) =
+            group_outputs(&outputs, NativeTokenConsolidationStrategy::Merge, false, 3).unwrap();
+        assert_eq!(group_inputs(&groups), [vec![0, 1, 2]]);
+        assert_eq!(groups[0].amount, 3_000_000);
+        assert_eq!(groups[0].native_tokens.len(), 2);
+        assert_eq!(skipped_outputs, 0);
+
+        let (groups, skipped_outputs) =
+            group_outputs(&outputs, NativeTokenConsolidationStrategy::Merge, false, 4).unwrap();
+        assert!(groups.is_empty());
+        assert_eq!(skipped_outputs, 3);
+
+        let (groups, _) = group_outputs(&outputs, NativeTokenConsolidationStrategy::Merge, true, 4).unwrap();
+        assert_eq!(group_inputs(&groups), [vec![0, 1, 2]]);
+
+        // Merging the second output would exceed the max native token count
+        let outputs = [output_data(0, 0..40), output_data(1, 40..80)];
+        let (groups, skipped_outputs) =
+            group_outputs(&outputs, NativeTokenConsolidationStrategy::Merge, true, 0).unwrap();
+        assert_eq!(group_inputs(&groups), [vec![0]]);
+        assert_eq!(skipped_outputs, 1);
+    }
+
+    #[test]
+    fn group_outputs_by_token_set() {
+        let outputs = [
+            output_data(0, []),
+            output_data(1, [1]),
+            output_data(2, []),
+            output_data(3, [1]),
+            output_data(4, [1]),
+            output_data(5, [2]),
+        ];
+
+        // The single output with token 2 isn't consolidated
+        let (groups, skipped_outputs) =
+            group_outputs(&outputs, NativeTokenConsolidationStrategy::GroupByTokenSet, false, 2).unwrap();
+        assert_eq!(group_inputs(&groups), [vec![0, 2], vec![1, 3, 4]]);
+        assert_eq!(skipped_outputs, 1);
+
+        // Every group needs to reach the threshold on its own
+        let (groups, skipped_outputs) =
+            group_outputs(&outputs, NativeTokenConsolidationStrategy::GroupByTokenSet, false, 3).unwrap();
+        assert_eq!(group_inputs(&groups), [vec![1, 3, 4]]);
+        assert_eq!(skipped_outputs, 3);
+
+        let (groups, skipped_outputs) =
+            group_outputs(&outputs, NativeTokenConsolidationStrategy::GroupByTokenSet, false, 4).unwrap();
+        assert!(groups.is_empty());
+        assert_eq!(skipped_outputs, 6);
+
+        let (groups, skipped_outputs) =
+            group_outputs(&outputs, NativeTokenConsolidationStrategy::GroupByTokenSet, true, 4).unwrap();
+        assert_eq!(group_inputs(&groups), [vec![0, 2], vec![1, 3, 4]]);
+        assert_eq!(skipped_outputs, 1);
+    }
+
+    #[test]
+    fn group_outputs_skip() {
+        let outputs = [
+            output_data(0, []),
+            output_data(1, [1]),
+            output_data(2, []),
+            output_data(3, [1]),
+        ];
+
+        let (groups, skipped_outputs) =
+            group_outputs(&outputs, NativeTokenConsolidationStrategy::Skip, false, 2).unwrap();
+        assert_eq!(group_inputs(&groups), [vec![0, 2]]);
+        assert_eq!(groups[0].native_tokens.len(), 0);
+        assert_eq!(skipped_outputs, 2);
 
-        self.prepare_transaction(consolidation_output, options).await
+        // The outputs without native tokens don't reach the threshold
+        let (groups, skipped_outputs) =
+            group_outputs(&outputs, NativeTokenConsolidationStrategy::Skip, false, 3).unwrap();
+        assert!(groups.is_empty());
+        assert_eq!(skipped_outputs, 4);
     }
 }
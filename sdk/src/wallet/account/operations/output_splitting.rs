@@ -0,0 +1,217 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    client::{api::PreparedTransactionData, secret::SecretManage},
+    types::block::{
+        address::Bech32Address,
+        output::{
+            unlock_condition::AddressUnlockCondition, BasicOutputBuilder, MinimumStorageDepositBasicOutput, Output,
+            OutputId, OUTPUT_COUNT_MAX,
+        },
+    },
+    wallet::{
+        account::{
+            operations::helpers::time::can_output_be_unlocked_now, types::Transaction, Account, TransactionOptions,
+        },
+        Error, Result,
+    },
+};
+
+/// The result of a transaction splitting an output
+#[derive(Debug)]
+pub struct SplitOutputTransaction {
+    /// The IDs of the created outputs, without the remainder
+    pub output_ids: Vec<OutputId>,
+    /// The sent transaction
+    pub transaction: Transaction,
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Splits a basic output into `count` basic outputs of `amount_each`, the inverse of
+    /// [Account::consolidate_outputs()](crate::wallet::Account::consolidate_outputs). The outputs are sent to the
+    /// `recipients` in turn or, if none are provided, back to the address of the source output. Only the source
+    /// output is used as input, whatever is left of it goes to a remainder output.
+    pub async fn split_output(
+        &self,
+        source: OutputId,
+        count: usize,
+        amount_each: u64,
+        recipients: Option<Vec<Bech32Address>>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> Result<SplitOutputTransaction> {
+        let options = options.into();
+        let prepared_transaction = self
+            .prepare_split_output(source, count, amount_each, recipients, options.clone())
+            .await?;
+        let transaction = self.sign_and_submit_transaction(prepared_transaction, options).await?;
+
+        log::debug!(
+            "[OUTPUT_SPLITTING] split transaction created: block_id: {:?} tx_id: {:?}",
+            transaction.block_id,
+            transaction.transaction_id
+        );
+
+        // Input selection keeps the requested outputs first, the remainder is appended after them.
+        let output_ids = (0..count as u16)
+            .map(|index| OutputId::new(transaction.transaction_id, index))
+            .collect::<core::result::Result<Vec<_>, _>>()?;
+
+        Ok(SplitOutputTransaction {
+            output_ids,
+            transaction,
+        })
+    }
+
+    /// Prepares the transaction for
+    /// [Account::split_output()](crate::wallet::Account::split_output).
+    pub async fn prepare_split_output(
+        &self,
+        source: OutputId,
+        count: usize,
+        amount_each: u64,
+        recipients: Option<Vec<Bech32Address>>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> Result<PreparedTransactionData> {
+        log::debug!("[OUTPUT_SPLITTING] prepare_split_output");
+
+        if count == 0 || count > OUTPUT_COUNT_MAX as usize {
+            return Err(Error::SplittingFailed(format!(
+                "output count {count} is not in the range 1..={OUTPUT_COUNT_MAX}"
+            )));
+        }
+
+        let (source_data, addresses_with_unspent_outputs) = {
+            let account_details = self.details().await;
+
+            if account_details.locked_outputs.contains(&source) {
+                return Err(Error::SplittingFailed(format!("output {source} is already used")));
+            }
+            let source_data = account_details
+                .unspent_outputs()
+                .get(&source)
+                .cloned()
+                .ok_or_else(|| Error::SplittingFailed(format!("output {source} is not an unspent output")))?;
+
+            (source_data, account_details.addresses_with_unspent_outputs.clone())
+        };
+
+        let current_time = self.client().get_time_checked().await?;
+        if !matches!(source_data.output, Output::Basic(_))
+            || !can_output_be_unlocked_now(&addresses_with_unspent_outputs, &[], &source_data, current_time, None)?
+        {
+            return Err(Error::SplittingFailed(format!(
+                "output {source} is not a basic output that can be unlocked now"
+            )));
+        }
+
+        let rent_structure = self.client().get_rent_structure().await?;
+        let token_supply = self.client().get_token_supply().await?;
+
+        let minimum_amount = MinimumStorageDepositBasicOutput::new(rent_structure, token_supply).finish()?;
+        if amount_each < minimum_amount {
+            return Err(Error::InsufficientFunds {
+                available: amount_each,
+                required: minimum_amount,
+            });
+        }
+
+        let source_amount = source_data.output.amount();
+        let required = amount_each
+            .checked_mul(count as u64)
+            .ok_or_else(|| Error::SplittingFailed("total amount overflows".to_string()))?;
+        if required > source_amount {
+            return Err(Error::InsufficientFunds {
+                available: source_amount,
+                required,
+            });
+        }
+
+        let recipients = match recipients {
+            Some(recipients) if !recipients.is_empty() => {
+                for recipient in &recipients {
+                    self.client().bech32_hrp_matches(recipient.hrp()).await?;
+                }
+                recipients.into_iter().map(|bech32| bech32.into_inner()).collect()
+            }
+            _ => vec![source_data.address],
+        };
+
+        let outputs = recipients
+            .iter()
+            .cycle()
+            .take(count)
+            .map(|address| {
+                BasicOutputBuilder::new_with_amount(amount_each)
+                    .add_unlock_condition(AddressUnlockCondition::new(*address))
+                    .finish_output(token_supply)
+            })
+            .collect::<core::result::Result<Vec<_>, _>>()?;
+
+        let options: Option<TransactionOptions> = options.into();
+        let options = TransactionOptions {
+            custom_inputs: Some(vec![source]),
+            // Only the source output may be split, other outputs must not be pulled in to cover the amount
+            allow_additional_input_selection: false,
+            ..options.unwrap_or_default()
+        };
+
+        self.prepare_transaction(outputs, options).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        types::block::{
+            address::{Ed25519Address, Hrp, ToBech32Ext},
+            payload::transaction::TransactionId,
+        },
+        wallet::Wallet,
+    };
+
+    #[tokio::test]
+    async fn prepare_split_output_rejects_invalid_requests() {
+        // Requests are validated before the node is needed, so an unroutable node is enough
+        let wallet = Wallet::for_test(
+            "prepare_split_output_rejects_invalid_requests",
+            "http://192.0.2.1:14265",
+        )
+        .await;
+        let account = wallet
+            .create_account()
+            .with_bech32_hrp(Hrp::from_str_unchecked("rms"))
+            .finish()
+            .await
+            .unwrap();
+        let source = OutputId::new(TransactionId::new([1; 32]), 0).unwrap();
+        let recipient = Ed25519Address::new([2; 32]).to_bech32(Hrp::from_str_unchecked("rms"));
+
+        for count in [0, OUTPUT_COUNT_MAX as usize + 1] {
+            assert!(matches!(
+                account.prepare_split_output(source, count, 1_000_000, None, None).await,
+                Err(Error::SplittingFailed(_))
+            ));
+        }
+        // An output that isn't an unspent output of the account can't be split
+        assert!(matches!(
+            account
+                .prepare_split_output(source, 2, 1_000_000, Some(vec![recipient]), None)
+                .await,
+            Err(Error::SplittingFailed(_))
+        ));
+
+        // The source output is locked while it's used
+        account.details_mut().await.locked_outputs.insert(source);
+        assert!(matches!(
+            account.prepare_split_output(source, 2, 1_000_000, None, None).await,
+            Err(Error::SplittingFailed(message)) if message.contains("already used")
+        ));
+
+        Wallet::remove_test_storage("prepare_split_output_rejects_invalid_requests");
+    }
+}
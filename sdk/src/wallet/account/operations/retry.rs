@@ -1,17 +1,29 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashMap;
+
+use serde::Serialize;
+
 use crate::{
-    client::{secret::SecretManage, Error as ClientError},
+    client::{error::PostBlockError, secret::SecretManage, Error as ClientError},
     types::{
-        api::core::response::LedgerInclusionState,
+        api::core::response::{BlockMetadataResponse, LedgerInclusionState},
         block::{
-            payload::{transaction::TransactionId, Payload},
+            input::Input,
+            output::OutputId,
+            payload::{
+                transaction::{TransactionEssence, TransactionId},
+                Payload,
+            },
             Block, BlockId,
         },
     },
     wallet::{
-        account::{types::InclusionState, Account},
+        account::{
+            types::{InclusionState, Transaction},
+            Account,
+        },
         Error,
     },
 };
@@ -19,6 +31,42 @@ use crate::{
 const DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL: u64 = 1;
 const DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT: u64 = 40;
 
+/// A block issued with a transaction while retrying it until it's included.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetryAttempt {
+    /// The id of the block.
+    pub block_id: BlockId,
+    /// The parents referenced by the block. Empty for an attachment that wasn't issued while retrying until its
+    /// metadata got fetched.
+    pub parents: Vec<BlockId>,
+    /// The latest metadata of the block, if it was fetched.
+    pub metadata: Option<BlockMetadataResponse>,
+}
+
+impl From<&Block> for RetryAttempt {
+    fn from(block: &Block) -> Self {
+        Self {
+            block_id: block.id(),
+            parents: block.parents().iter().copied().collect(),
+            metadata: None,
+        }
+    }
+}
+
+/// The report of retrying a transaction until it's included.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetryTransactionReport {
+    /// Whether the transaction got included.
+    pub included: bool,
+    /// The id of the block that got the transaction included. Not set if the transaction didn't get included or if
+    /// the node pruned the block.
+    pub included_block_id: Option<BlockId>,
+    /// The blocks issued with the transaction, starting with the original attachment.
+    pub attempts: Vec<RetryAttempt>,
+}
+
 impl<S: 'static + SecretManage> Account<S>
 where
     Error: From<S::Error>,
@@ -48,92 +96,359 @@ where
     ) -> crate::wallet::Result<BlockId> {
         log::debug!("[retry_transaction_until_included]");
 
-        let transaction = self.details().await.transactions.get(transaction_id).cloned();
+        let report = self
+            .retry_transaction_until_included_with_report(transaction_id, interval, max_attempts, None)
+            .await?;
 
-        if let Some(transaction) = transaction {
-            if transaction.inclusion_state == InclusionState::Confirmed {
-                return transaction.block_id.ok_or(Error::MissingParameter("block id"));
-            }
+        report.into_included_block_id(transaction_id)
+    }
 
-            if transaction.inclusion_state == InclusionState::Conflicting
-                || transaction.inclusion_state == InclusionState::UnknownPruned
-            {
-                return Err(ClientError::TangleInclusion(format!(
-                    "transaction id: {} inclusion state: {:?}",
-                    transaction_id, transaction.inclusion_state
-                ))
-                .into());
-            }
+    /// Retries (promotes or reattaches) a transaction sent from the account for a provided transaction id until it's
+    /// included (referenced by a milestone), like [`Account::retry_transaction_until_included()`].
+    /// If `parents` are provided, they're referenced by the reattached blocks instead of the tips selected by the node.
+    /// Returns a report of every block issued with the transaction, with its parents and latest metadata. The included
+    /// block id isn't set if the transaction didn't get included within `max_attempts`, or if the node pruned the
+    /// block after the transaction got included.
+    pub async fn retry_transaction_until_included_with_report(
+        &self,
+        transaction_id: &TransactionId,
+        interval: Option<u64>,
+        max_attempts: Option<u64>,
+        parents: Option<Vec<BlockId>>,
+    ) -> crate::wallet::Result<RetryTransactionReport> {
+        log::debug!("[retry_transaction_until_included_with_report]");
+
+        let transaction = self
+            .details()
+            .await
+            .transactions
+            .get(transaction_id)
+            .cloned()
+            .ok_or(Error::TransactionNotFound(*transaction_id))?;
+        let mut report = RetryTransactionReport::default();
+
+        if transaction.inclusion_state == InclusionState::Confirmed {
+            report.included = true;
+            report.included_block_id = Some(transaction.block_id.ok_or(Error::MissingParameter("block id"))?);
+            return Ok(report);
+        }
 
-            let block_id = match transaction.block_id {
-                Some(block_id) => block_id,
-                None => self
+        if transaction.inclusion_state == InclusionState::Conflicting
+            || transaction.inclusion_state == InclusionState::UnknownPruned
+        {
+            return Err(ClientError::TangleInclusion(format!(
+                "transaction id: {} inclusion state: {:?}",
+                transaction_id, transaction.inclusion_state
+            ))
+            .into());
+        }
+
+        let first_input = first_input(&transaction);
+        let payload = Payload::Transaction(Box::new(transaction.payload));
+
+        // Attachments of the Block to check inclusion state
+        let attempt = match transaction.block_id {
+            // The parents are taken from the metadata, so a block that's already pruned doesn't need to be fetched
+            Some(block_id) => RetryAttempt {
+                block_id,
+                parents: Vec::new(),
+                metadata: None,
+            },
+            None => RetryAttempt::from(
+                &self
                     .client()
                     .build_block()
-                    .finish_block(Some(Payload::Transaction(Box::new(transaction.payload.clone()))))
-                    .await?
-                    .id(),
-            };
-
-            // Attachments of the Block to check inclusion state
-            let mut block_ids = vec![block_id];
-            for _ in 0..max_attempts.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT) {
-                let duration =
-                    std::time::Duration::from_secs(interval.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL));
-
-                #[cfg(target_family = "wasm")]
-                gloo_timers::future::TimeoutFuture::new(duration.as_millis() as u32).await;
-
-                #[cfg(not(target_family = "wasm"))]
-                tokio::time::sleep(duration).await;
-
-                // Check inclusion state for each attachment
-                let block_ids_len = block_ids.len();
-                let mut conflicting = false;
-                for (index, block_id_) in block_ids.clone().iter().enumerate() {
-                    let block_metadata = self.client().get_block_metadata(block_id_).await?;
-                    if let Some(inclusion_state) = block_metadata.ledger_inclusion_state {
-                        match inclusion_state {
-                            LedgerInclusionState::Included | LedgerInclusionState::NoTransaction => {
-                                return Ok(*block_id_);
+                    .with_parents(parents.clone())?
+                    .finish_block(Some(payload.clone()))
+                    .await
+                    .map_err(PostBlockError::from)?,
+            ),
+        };
+        report.attempts.push(attempt);
+
+        for _ in 0..max_attempts.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT) {
+            let duration = std::time::Duration::from_secs(interval.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL));
+
+            #[cfg(target_family = "wasm")]
+            gloo_timers::future::TimeoutFuture::new(duration.as_millis() as u32).await;
+
+            #[cfg(not(target_family = "wasm"))]
+            tokio::time::sleep(duration).await;
+
+            // Check inclusion state for each attachment
+            let attempts_len = report.attempts.len();
+            let mut conflicting = false;
+            for index in 0..attempts_len {
+                let block_id = report.attempts[index].block_id;
+                let block_metadata = match self.client().get_block_metadata(&block_id).await {
+                    Ok(block_metadata) => block_metadata,
+                    Err(ClientError::Node(crate::client::node_api::error::Error::NotFound(e))) => {
+                        // The node may have pruned the block after the transaction got included
+                        if let Some(first_input) = &first_input {
+                            if self.is_spent_by(first_input, transaction_id).await? {
+                                report.included = true;
+                                report.included_block_id = self.included_block_id(transaction_id).await?;
+                                return Ok(report);
                             }
-                            // only set it as conflicting here and don't return, because another reattached block could
-                            // have the included transaction
-                            LedgerInclusionState::Conflicting => conflicting = true,
-                        };
+                        }
+                        return Err(ClientError::Node(crate::client::node_api::error::Error::NotFound(e)).into());
                     }
-                    // Only reattach or promote latest attachment of the block
-                    if index == block_ids_len - 1 {
-                        if block_metadata.should_promote.unwrap_or(false) {
-                            // Safe to unwrap since we iterate over it
-                            self.client().promote_unchecked(block_ids.last().unwrap()).await?;
-                        } else if block_metadata.should_reattach.unwrap_or(false) {
-                            let reattached_block = self
-                                .client()
-                                .build_block()
-                                .finish_block(Some(Payload::Transaction(Box::new(transaction.payload.clone()))))
-                                .await?;
-                            block_ids.push(reattached_block.id());
+                    Err(e) => return Err(e.into()),
+                };
+                let should_promote = block_metadata.should_promote.unwrap_or(false);
+                let should_reattach = block_metadata.should_reattach.unwrap_or(false);
+                let ledger_inclusion_state = block_metadata.ledger_inclusion_state;
+                if report.attempts[index].parents.is_empty() {
+                    report.attempts[index].parents = block_metadata.parents.clone();
+                }
+                report.attempts[index].metadata = Some(block_metadata);
+
+                if let Some(inclusion_state) = ledger_inclusion_state {
+                    match inclusion_state {
+                        LedgerInclusionState::Included | LedgerInclusionState::NoTransaction => {
+                            report.included = true;
+                            report.included_block_id = Some(block_id);
+                            return Ok(report);
                         }
+                        // only set it as conflicting here and don't return, because another reattached block could
+                        // have the included transaction
+                        LedgerInclusionState::Conflicting => conflicting = true,
+                    };
+                }
+                // Only reattach or promote latest attachment of the block
+                if index == attempts_len - 1 {
+                    if should_promote {
+                        self.client()
+                            .promote_unchecked(&block_id)
+                            .await
+                            .map_err(PostBlockError::from)?;
+                    } else if should_reattach {
+                        let reattached_block = self
+                            .client()
+                            .build_block()
+                            .with_parents(parents.clone())?
+                            .finish_block(Some(payload.clone()))
+                            .await
+                            .map_err(PostBlockError::from)?;
+                        report.attempts.push(RetryAttempt::from(&reattached_block));
                     }
                 }
-                // After we checked all our reattached blocks, check if the transaction got reattached in another block
-                // and confirmed
-                if conflicting {
-                    let included_block = self.client().get_included_block(transaction_id).await.map_err(|e| {
-                        if matches!(e, ClientError::Node(crate::client::node_api::error::Error::NotFound(_))) {
-                            // If no block was found with this transaction id, then it can't get included
-                            ClientError::TangleInclusion(block_id.to_string())
-                        } else {
-                            e
+            }
+            // After we checked all our reattached blocks, check if the transaction got reattached in another block
+            // and confirmed
+            if conflicting {
+                let included_block = self.client().get_included_block(transaction_id).await.map_err(|e| {
+                    if matches!(e, ClientError::Node(crate::client::node_api::error::Error::NotFound(_))) {
+                        // If no block was found with this transaction id, then it can't get included
+                        ClientError::TangleInclusion(report.attempts[0].block_id.to_string())
+                    } else {
+                        e
+                    }
+                })?;
+                report.included = true;
+                report.included_block_id = Some(included_block.id());
+                return Ok(report);
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Waits until all provided transactions are included (referenced by a milestone), sharing one polling loop. Unlike
+    /// [`Account::retry_transaction_until_included()`], blocks are neither promoted nor reattached.
+    /// Returns the included block id or the error for every transaction. If `fail_fast` is set, the first conflicting
+    /// transaction returns the error right away.
+    pub async fn wait_for_transactions_inclusion(
+        &self,
+        transaction_ids: &[TransactionId],
+        interval: Option<u64>,
+        max_attempts: Option<u64>,
+        fail_fast: bool,
+    ) -> crate::wallet::Result<HashMap<TransactionId, crate::wallet::Result<BlockId>>> {
+        log::debug!("[wait_for_transactions_inclusion]");
+
+        let mut results = HashMap::with_capacity(transaction_ids.len());
+        let mut pending = Vec::with_capacity(transaction_ids.len());
+        // First input of the pending transactions known to the account
+        let mut first_inputs = HashMap::new();
+
+        {
+            let account_details = self.details().await;
+            for transaction_id in transaction_ids {
+                match account_details.transactions.get(transaction_id) {
+                    Some(transaction) if transaction.inclusion_state == InclusionState::Confirmed => {
+                        results.insert(
+                            *transaction_id,
+                            transaction.block_id.ok_or(Error::MissingParameter("block id")),
+                        );
+                    }
+                    transaction => {
+                        if let Some(transaction) = transaction {
+                            first_inputs.insert(*transaction_id, first_input(transaction));
                         }
-                    })?;
-                    return Ok(included_block.id());
+                        pending.push(*transaction_id);
+                    }
+                }
+            }
+        }
+
+        for _ in 0..max_attempts.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT) {
+            if pending.is_empty() {
+                break;
+            }
+
+            let duration = std::time::Duration::from_secs(interval.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL));
+
+            #[cfg(target_family = "wasm")]
+            gloo_timers::future::TimeoutFuture::new(duration.as_millis() as u32).await;
+
+            #[cfg(not(target_family = "wasm"))]
+            tokio::time::sleep(duration).await;
+
+            let mut included_blocks_metadata = self.client().get_included_blocks_metadata(&pending).await?;
+
+            pending.retain(|transaction_id| {
+                let Some(block_metadata) = included_blocks_metadata.remove(transaction_id) else {
+                    return true;
+                };
+                match block_metadata.ledger_inclusion_state {
+                    Some(LedgerInclusionState::Included | LedgerInclusionState::NoTransaction) => {
+                        results.insert(*transaction_id, Ok(block_metadata.block_id));
+                        false
+                    }
+                    Some(LedgerInclusionState::Conflicting) => {
+                        results.insert(
+                            *transaction_id,
+                            Err(ClientError::TangleInclusion(format!(
+                                "transaction id: {transaction_id} inclusion state: {:?}",
+                                InclusionState::Conflicting
+                            ))
+                            .into()),
+                        );
+                        false
+                    }
+                    None => true,
+                }
+            });
+
+            if fail_fast {
+                if let Some(transaction_id) = results
+                    .iter()
+                    .find_map(|(transaction_id, result)| result.is_err().then_some(*transaction_id))
+                {
+                    // Safe to unwrap since we just found it
+                    return Err(results.remove(&transaction_id).unwrap().unwrap_err());
+                }
+            }
+        }
+
+        for transaction_id in pending {
+            // The node may have pruned the block after the transaction got included
+            if let Some(Some(first_input)) = first_inputs.get(&transaction_id) {
+                if self.is_spent_by(first_input, &transaction_id).await? {
+                    let block_id = self.included_block_id(&transaction_id).await?;
+                    results.insert(
+                        transaction_id,
+                        block_id.ok_or(Error::IncludedBlockPruned(transaction_id)),
+                    );
+                    continue;
                 }
             }
-            Err(ClientError::TangleInclusion(block_id.to_string()).into())
-        } else {
-            Err(Error::TransactionNotFound(*transaction_id))
+            results.insert(
+                transaction_id,
+                Err(ClientError::TangleInclusion(transaction_id.to_string()).into()),
+            );
         }
+
+        Ok(results)
+    }
+
+    // Checks if an output got spent by the transaction, which means the transaction got included even if the node
+    // doesn't know its block anymore.
+    async fn is_spent_by(&self, output_id: &OutputId, transaction_id: &TransactionId) -> crate::wallet::Result<bool> {
+        match self.client().get_output_metadata(output_id).await {
+            Ok(metadata) => Ok(metadata.transaction_id_spent() == Some(transaction_id)),
+            Err(ClientError::Node(crate::client::node_api::error::Error::NotFound(_))) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    // Gets the id of the block that included the transaction, if the node didn't prune it.
+    async fn included_block_id(&self, transaction_id: &TransactionId) -> crate::wallet::Result<Option<BlockId>> {
+        match self.client().get_included_block_metadata(transaction_id).await {
+            Ok(metadata) => Ok(Some(metadata.block_id)),
+            Err(ClientError::Node(crate::client::node_api::error::Error::NotFound(_))) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+impl RetryTransactionReport {
+    // The included block id, or the error for a transaction that didn't get included or whose block got pruned.
+    fn into_included_block_id(self, transaction_id: &TransactionId) -> crate::wallet::Result<BlockId> {
+        match (self.included_block_id, self.included) {
+            (Some(block_id), _) => Ok(block_id),
+            (None, true) => Err(Error::IncludedBlockPruned(*transaction_id)),
+            // Safe to index since a block with the transaction was issued if it didn't get included
+            (None, false) => Err(ClientError::TangleInclusion(self.attempts[0].block_id.to_string()).into()),
+        }
+    }
+}
+
+// All inputs are spent by the same transaction, so checking the first one is enough.
+fn first_input(transaction: &Transaction) -> Option<OutputId> {
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    essence.inputs().iter().find_map(|input| match input {
+        Input::Utxo(input) => Some(*input.output_id()),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn included_block_id_of_report() {
+        let transaction_id = TransactionId::new([1; 32]);
+        let attempt = RetryAttempt {
+            block_id: BlockId::new([2; 32]),
+            parents: Vec::new(),
+            metadata: None,
+        };
+        let included_block_id = BlockId::new([3; 32]);
+
+        let included = RetryTransactionReport {
+            included: true,
+            included_block_id: Some(included_block_id),
+            attempts: vec![attempt.clone()],
+        };
+        assert_eq!(
+            included.into_included_block_id(&transaction_id).unwrap(),
+            included_block_id
+        );
+
+        // The transaction got included, but the node doesn't know the block anymore, so no block id is claimed
+        let pruned = RetryTransactionReport {
+            included: true,
+            included_block_id: None,
+            attempts: vec![attempt.clone()],
+        };
+        assert!(matches!(
+            pruned.into_included_block_id(&transaction_id),
+            Err(Error::IncludedBlockPruned(id)) if id == transaction_id
+        ));
+
+        let not_included = RetryTransactionReport {
+            included: false,
+            included_block_id: None,
+            attempts: vec![attempt],
+        };
+        assert!(matches!(
+            not_included.into_included_block_id(&transaction_id),
+            Err(Error::Client(e)) if matches!(*e, ClientError::TangleInclusion(_))
+        ));
     }
 }
@@ -10,6 +10,9 @@ pub(crate) mod transactions;
 use std::collections::{HashMap, HashSet};
 
 pub use self::options::SyncOptions;
+use self::options::AutoConsolidateOptions;
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{TransactionInclusionEvent, WalletEvent};
 use crate::{
     client::secret::SecretManage,
     types::block::{
@@ -18,8 +21,8 @@ use crate::{
     },
     wallet::account::{
         constants::MIN_SYNC_INTERVAL,
-        types::{AddressWithUnspentOutputs, OutputData},
-        Account, Balance,
+        types::{AddressWithUnspentOutputs, OutputData, Transaction},
+        Account, Balance, ConsolidationParams,
     },
 };
 
@@ -48,48 +51,133 @@ where
 
     /// Sync the account by fetching new information from the nodes. Will also retry pending transactions
     /// if necessary. A custom default can be set using set_default_sync_options.
+    ///
+    /// It's safe to call this from multiple tasks: syncs of the same account never run concurrently, and a sync that
+    /// had to wait for another one with the same options returns the balance right away instead of syncing again,
+    /// unless `force_syncing` is set.
     pub async fn sync(&self, options: Option<SyncOptions>) -> crate::wallet::Result<Balance> {
+        Ok(self.sync_and_consolidate(options).await?.0)
+    }
+
+    /// Sync the account like [`Account::sync()`] and also return the consolidation transaction, if
+    /// [`SyncOptions::auto_consolidate`] is set and there were enough outputs to consolidate. Failing to consolidate
+    /// doesn't fail the sync, the error is only logged.
+    pub async fn sync_and_consolidate(
+        &self,
+        options: Option<SyncOptions>,
+    ) -> crate::wallet::Result<(Balance, Option<Transaction>)> {
         let options = match options {
             Some(opt) => opt,
             None => self.default_sync_options().await,
         };
+        let auto_consolidate = options.auto_consolidate.clone();
+
+        let (balance, synced) = self.sync_exclusively(options).await?;
+
+        // Consolidate after the sync released its lock, so other syncs don't have to wait for the transaction
+        if let (true, Some(auto_consolidate)) = (synced, auto_consolidate) {
+            if let Some(transaction) = self.auto_consolidate(&auto_consolidate).await {
+                // The consolidated outputs aren't available anymore
+                return Ok((self.balance().await?, Some(transaction)));
+            }
+        }
+
+        Ok((balance, None))
+    }
 
+    // Syncs the account unless another sync with the same options just finished, returns the balance and whether the
+    // account was synced.
+    async fn sync_exclusively(&self, options: SyncOptions) -> crate::wallet::Result<(Balance, bool)> {
         log::debug!("[SYNC] start syncing with {:?}", options);
         let syc_start_time = instant::Instant::now();
 
         // Prevent syncing the account multiple times simultaneously
-        let time_now = crate::utils::unix_timestamp_now().as_millis();
-        let mut last_synced = self.last_synced.lock().await;
-        log::debug!("[SYNC] last time synced before {}ms", time_now - *last_synced);
-        if !options.force_syncing && time_now - *last_synced < MIN_SYNC_INTERVAL {
+        let (mut last_synced, waited_for_sync) = match self.last_synced.try_lock() {
+            Ok(last_synced) => (last_synced, false),
+            Err(_) => {
+                log::debug!("[SYNC] waiting for the sync in progress");
+                (self.last_synced.lock().await, true)
+            }
+        };
+        let (last_synced_time, last_sync_options) = &*last_synced;
+        // Read the time after getting the lock, the sync we waited for could have finished after an earlier timestamp
+        let time_now = self.client().unix_timestamp_now().as_millis();
+        let elapsed = time_now.saturating_sub(*last_synced_time);
+        log::debug!("[SYNC] last time synced before {}ms", elapsed);
+        let same_options = last_sync_options.as_ref() == Some(&options);
+        if !options.force_syncing && (elapsed < MIN_SYNC_INTERVAL || (waited_for_sync && same_options)) {
             log::debug!(
-                "[SYNC] synced within the latest {} ms, only calculating balance",
+                "[SYNC] synced within the latest {} ms or by the sync we waited for, only calculating balance",
                 MIN_SYNC_INTERVAL
             );
             // Calculate the balance because if we created a transaction in the meantime, the amount for the inputs is
             // not available anymore
-            return self.balance().await;
+            return Ok((self.balance().await?, false));
         }
 
-        self.sync_internal(&options).await?;
+        let result: crate::wallet::Result<Balance> = async {
+            self.sync_internal(&options).await?;
+
+            // Sync transactions after updating account with outputs, so we can use them to check the transaction
+            // status
+            if options.sync_pending_transactions {
+                let confirmed_tx_with_unknown_output = self.sync_pending_transactions().await?;
+                // Sync again if we don't know the output yet, to prevent having no unspent outputs after syncing
+                if confirmed_tx_with_unknown_output {
+                    log::debug!(
+                        "[SYNC] a transaction for which no output is known got confirmed, syncing outputs again"
+                    );
+                    self.sync_internal(&options).await?;
+                }
+            };
 
-        // Sync transactions after updating account with outputs, so we can use them to check the transaction
-        // status
-        if options.sync_pending_transactions {
-            let confirmed_tx_with_unknown_output = self.sync_pending_transactions().await?;
-            // Sync again if we don't know the output yet, to prevent having no unspent outputs after syncing
-            if confirmed_tx_with_unknown_output {
-                log::debug!("[SYNC] a transaction for which no output is known got confirmed, syncing outputs again");
-                self.sync_internal(&options).await?;
+            self.balance().await
+        }
+        .await;
+
+        // Update last_synced mutex, only a successful sync can be reused by the syncs waiting for it
+        match &result {
+            Ok(_) => {
+                let time_now = self.client().unix_timestamp_now().as_millis();
+                *last_synced = (time_now, Some(options));
+                log::debug!("[SYNC] finished syncing in {:.2?}", syc_start_time.elapsed());
             }
-        };
+            Err(_) => last_synced.1 = None,
+        }
 
-        let balance = self.balance().await?;
-        // Update last_synced mutex
-        let time_now = crate::utils::unix_timestamp_now().as_millis();
-        *last_synced = time_now;
-        log::debug!("[SYNC] finished syncing in {:.2?}", syc_start_time.elapsed());
-        Ok(balance)
+        Ok((result?, true))
+    }
+
+    // Consolidates the outputs if there are enough of them, not having enough isn't an error. Other errors are only
+    // logged, so they don't fail the sync.
+    async fn auto_consolidate(&self, options: &AutoConsolidateOptions) -> Option<Transaction> {
+        let params = ConsolidationParams::new().with_output_threshold(options.output_threshold);
+        match self.consolidate_outputs(params).await {
+            Ok(transaction) => {
+                log::debug!(
+                    "[SYNC] auto consolidation transaction created: {}",
+                    transaction.transaction_id
+                );
+                #[cfg(feature = "events")]
+                {
+                    let account_index = *self.details().await.index();
+                    self.emit(
+                        account_index,
+                        WalletEvent::TransactionInclusion(TransactionInclusionEvent {
+                            transaction_id: transaction.transaction_id,
+                            inclusion_state: transaction.inclusion_state,
+                        }),
+                    )
+                    .await;
+                }
+                Some(transaction)
+            }
+            Err(crate::wallet::Error::NoOutputsToConsolidate { .. }) => None,
+            Err(e) => {
+                log::warn!("[SYNC] auto consolidation failed: {e}");
+                None
+            }
+        }
     }
 
     async fn sync_internal(&self, options: &SyncOptions) -> crate::wallet::Result<()> {
@@ -237,3 +325,42 @@ where
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use super::*;
+    use crate::{client::clock::MockClock, types::block::address::Hrp, wallet::Wallet};
+
+    #[tokio::test]
+    async fn sync_skips_recent_sync_and_forgets_failed_sync() {
+        // Nothing can listen on port 0, so requesting the node always fails
+        let wallet = Wallet::for_test("sync_skips_recent_sync_and_forgets_failed_sync", "http://127.0.0.1:0").await;
+        let account = wallet
+            .create_account()
+            .with_bech32_hrp(Hrp::from_str_unchecked("rms"))
+            .finish()
+            .await
+            .unwrap();
+
+        let clock = Arc::new(MockClock::new(Duration::from_secs(1_700_000_000)));
+        account.client().set_clock(clock.clone());
+        let synced_at = account.client().unix_timestamp_now().as_millis();
+        *account.last_synced.lock().await = (synced_at, Some(SyncOptions::default()));
+
+        // Within the minimum interval the sync is skipped, so the node isn't needed
+        let balance = account.sync(Some(SyncOptions::default())).await.unwrap();
+        assert_eq!(balance, account.balance().await.unwrap());
+
+        // Afterwards the node is requested, which fails and must not be recorded as a sync
+        clock.advance(Duration::from_millis(MIN_SYNC_INTERVAL as u64));
+        assert!(account.sync(Some(SyncOptions::default())).await.is_err());
+        let last_synced = account.last_synced.lock().await;
+        assert_eq!(last_synced.0, synced_at);
+        assert!(last_synced.1.is_none());
+        drop(last_synced);
+
+        Wallet::remove_test_storage("sync_skips_recent_sync_and_forgets_failed_sync");
+    }
+}
@@ -1,6 +1,8 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use core::ops::RangeInclusive;
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::block::address::Bech32Address;
@@ -56,6 +58,15 @@ pub struct SyncOptions {
     /// Sync native token foundries, so their metadata can be returned in the balance.
     #[serde(default = "default_sync_native_token_foundries")]
     pub sync_native_token_foundries: bool,
+    /// Only outputs with an amount within this range are considered unspent by the account. Outputs outside of it are
+    /// excluded from the balance and won't be selected as inputs for transactions, even though the funds are still
+    /// there. Syncing again without a range makes them available again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount_range: Option<RangeInclusive<u64>>,
+    /// Consolidates the outputs of the account at the end of the sync if there are enough of them. This submits a
+    /// transaction and is disabled by default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_consolidate: Option<AutoConsolidateOptions>,
 }
 
 fn default_address_start_index() -> u32 {
@@ -96,10 +107,21 @@ impl Default for SyncOptions {
             sync_only_most_basic_outputs: default_sync_only_most_basic_outputs(),
             sync_native_token_foundries: default_sync_native_token_foundries(),
             force_syncing: default_force_syncing(),
+            amount_range: None,
+            auto_consolidate: None,
         }
     }
 }
 
+/// Options for the consolidation done at the end of a sync
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AutoConsolidateOptions {
+    /// Consolidates if the number of outputs that can be consolidated is >= the output_threshold. Uses the same
+    /// default as [`Account::consolidate_outputs()`](crate::wallet::Account::consolidate_outputs) if not set.
+    pub output_threshold: Option<usize>,
+}
+
 /// Sync options for Ed25519 addresses from the account
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 #[serde(default, rename_all = "camelCase")]
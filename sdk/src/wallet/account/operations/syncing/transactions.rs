@@ -7,7 +7,6 @@ use crate::{
         api::core::response::LedgerInclusionState,
         block::{input::Input, output::OutputId, payload::transaction::TransactionEssence, BlockId},
     },
-    utils::unix_timestamp_now,
     wallet::account::{
         types::{InclusionState, Transaction},
         Account, AccountDetails,
@@ -160,7 +159,7 @@ where
                                     &mut output_ids_to_unlock,
                                 )?;
                             } else {
-                                let time_now = unix_timestamp_now().as_millis();
+                                let time_now = self.client().unix_timestamp_now().as_millis();
                                 // Reattach if older than 30 seconds
                                 if transaction.timestamp + 30000 < time_now {
                                     // only reattach if inputs are still unspent
@@ -179,7 +178,7 @@ where
                                 &mut output_ids_to_unlock,
                             )?;
                         } else {
-                            let time_now = unix_timestamp_now().as_millis();
+                            let time_now = self.client().unix_timestamp_now().as_millis();
                             // Reattach if older than 30 seconds
                             if transaction.timestamp + 30000 < time_now {
                                 // only reattach if inputs are still unspent
@@ -39,6 +39,9 @@ where
 
     /// Prepares the transaction for
     /// [Account::melt_native_token()](crate::wallet::Account::melt_native_token).
+    ///
+    /// The foundry which minted the native token is looked up in the unspent outputs of the account, if it's not there
+    /// [`Error::FoundryNotFound`] is returned. At most the circulating supply of the native token can be melted.
     pub async fn prepare_melt_native_token(
         &self,
         token_id: TokenId,
@@ -49,7 +52,7 @@ where
 
         let foundry_id = FoundryId::from(token_id);
         let alias_id = *foundry_id.alias_address().alias_id();
-        let token_supply = self.client().get_token_supply().await?;
+        let melt_amount = melt_amount.into();
 
         let (existing_alias_output_data, existing_foundry_output) = self
             .find_alias_and_foundry_output_data(alias_id, foundry_id)
@@ -59,6 +62,17 @@ where
                 _ => unreachable!("We already checked it's a foundry output"),
             })?;
 
+        let TokenScheme::Simple(token_scheme) = existing_foundry_output.token_scheme();
+        // Melting the whole circulating supply is allowed, so the foundry can be destroyed afterwards
+        if melt_amount > token_scheme.circulating_supply() {
+            return Err(Error::BurningOrMeltingFailed(format!(
+                "can't melt {melt_amount} tokens, the circulating supply of {token_id} is {}",
+                token_scheme.circulating_supply()
+            )));
+        }
+
+        let token_supply = self.client().get_token_supply().await?;
+
         if let Output::Alias(alias_output) = &existing_alias_output_data.output {
             // Create the new alias output with updated amount and state_index
             let alias_output = AliasOutputBuilder::from(alias_output)
@@ -66,7 +80,6 @@ where
                 .with_state_index(alias_output.state_index() + 1)
                 .finish_output(token_supply)?;
 
-            let TokenScheme::Simple(token_scheme) = existing_foundry_output.token_scheme();
             let outputs = [
                 alias_output,
                 FoundryOutputBuilder::from(&existing_foundry_output)
@@ -114,12 +127,12 @@ where
             }
         }
 
+        let existing_foundry_output_data =
+            existing_foundry_output.ok_or_else(|| Error::FoundryNotFound(TokenId::from(foundry_id)))?;
+
         let existing_alias_output_data = existing_alias_output_data
             .ok_or_else(|| Error::BurningOrMeltingFailed("required alias output for foundry not found".to_string()))?;
 
-        let existing_foundry_output_data = existing_foundry_output
-            .ok_or_else(|| Error::BurningOrMeltingFailed("required foundry output not found".to_string()))?;
-
         Ok((existing_alias_output_data, existing_foundry_output_data))
     }
 }
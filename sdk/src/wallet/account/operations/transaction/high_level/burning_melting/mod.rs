@@ -1,22 +1,87 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashSet;
+
+use serde::Serialize;
+
 use crate::{
     client::api::{input_selection::Burn, PreparedTransactionData},
+    types::block::{
+        address::{Address, AliasAddress},
+        output::{FoundryId, NativeToken, Output, OutputId, TokenId},
+        payload::transaction::TransactionEssence,
+    },
     wallet::{
-        account::{types::Transaction, TransactionOptions},
-        Account,
+        account::{
+            types::{Balance, Transaction},
+            TransactionOptions,
+        },
+        Account, Error,
     },
+    U256,
 };
 
 pub(crate) mod melt_native_token;
 
+/// A prepared burn which is only signed and submitted once [`BurnHandle::confirm()`] is called, since burning is
+/// irreversible.
+#[derive(Debug)]
+#[must_use = "nothing is burned until the burn is confirmed"]
+pub struct BurnHandle {
+    account: Account,
+    prepared_transaction_data: PreparedTransactionData,
+    options: Option<TransactionOptions>,
+}
+
+impl BurnHandle {
+    /// Returns the prepared transaction, so it can be inspected before confirming the burn.
+    pub fn prepared_transaction_data(&self) -> &PreparedTransactionData {
+        &self.prepared_transaction_data
+    }
+
+    /// Signs and submits the burn transaction.
+    pub async fn confirm(self) -> crate::wallet::Result<Transaction> {
+        self.account
+            .sign_and_submit_transaction(self.prepared_transaction_data, self.options)
+            .await
+    }
+}
+
+/// The expected outcome of a burn, see [`Account::estimate_burn()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BurnEstimate {
+    /// The aliases, foundries and NFTs which would be destroyed.
+    pub destroyed_outputs: Vec<OutputId>,
+    /// The amount of the destroyed outputs, which is sent back to the account.
+    pub released_storage_deposit: u64,
+    /// The native tokens which would be burned. This doesn't increase the `melted_tokens` field of their foundries.
+    pub burned_native_tokens: Vec<NativeToken>,
+}
+
+/// A prepared burn of all native tokens, see [`Account::prepare_burn_all_native_tokens()`].
+#[derive(Clone, Debug)]
+pub struct PreparedNativeTokensBurn {
+    /// The prepared burn transaction.
+    pub prepared_transaction_data: PreparedTransactionData,
+    /// The native tokens which aren't burned because their foundry is available, they should be melted instead.
+    pub skipped_token_ids: Vec<TokenId>,
+}
+
 impl Account {
     /// A generic function that can be used to burn native tokens, nfts, foundries and aliases.
     ///
+    /// Burning is irreversible and the transaction is signed and submitted right away, use
+    /// [`Account::burn_with_confirmation()`] to inspect the burn before it's signed.
+    ///
     /// Note that burning **native tokens** doesn't require the foundry output which minted them, but will not increase
     /// the foundries `melted_tokens` field, which makes it impossible to destroy the foundry output. Therefore it's
     /// recommended to use melting, if the foundry output is available.
+    ///
+    /// Besides a [`Burn`], a single [`AliasId`](crate::types::block::output::AliasId),
+    /// [`NftId`](crate::types::block::output::NftId), [`FoundryId`](crate::types::block::output::FoundryId),
+    /// [`NativeToken`](crate::types::block::output::NativeToken) or `(TokenId, U256)` can be passed directly.
     pub async fn burn(
         &self,
         burn: impl Into<Burn> + Send,
@@ -28,22 +93,264 @@ impl Account {
         self.sign_and_submit_transaction(prepared, options).await
     }
 
+    /// Like [`Account::burn()`], but only prepares the transaction and returns a [`BurnHandle`], so nothing is burned
+    /// until [`BurnHandle::confirm()`] is called.
+    pub async fn burn_with_confirmation(
+        &self,
+        burn: impl Into<Burn> + Send,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<BurnHandle> {
+        let options = options.into();
+        let prepared_transaction_data = self.prepare_burn(burn, options.clone()).await?;
+
+        Ok(BurnHandle {
+            account: self.clone(),
+            prepared_transaction_data,
+            options,
+        })
+    }
+
     /// A generic `prepare_burn()` function that can be used to prepare the burn of native tokens, nfts, foundries and
     /// aliases.
     ///
     /// Note that burning **native tokens** doesn't require the foundry output which minted them, but will not increase
     /// the foundries `melted_tokens` field, which makes it impossible to destroy the foundry output. Therefore it's
     /// recommended to use melting, if the foundry output is available.
+    ///
+    /// When only a part of the native tokens of the inputs is burned, the rest is sent back to the account in the
+    /// remainder output, see [`PreparedTransactionData::remainder`].
+    ///
+    /// Burning an alias that still controls foundries or NFTs known to the account fails with
+    /// [`Error::AliasControlsOutputs`], unless [`TransactionOptions::allow_orphaning`] is set.
+    ///
+    /// Burning more native tokens than available in the account fails with [`Error::InsufficientNativeTokens`] before
+    /// any input is selected.
     pub async fn prepare_burn(
         &self,
         burn: impl Into<Burn> + Send,
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<PreparedTransactionData> {
         let mut options: TransactionOptions = options.into().unwrap_or_default();
-        options.burn = Some(burn.into());
+        let burn = burn.into();
+
+        if !options.allow_orphaning {
+            self.check_no_orphaned_outputs(&burn).await?;
+        }
+        self.check_native_tokens_available(&burn).await?;
+
+        options.burn = Some(burn);
 
         // The empty list of outputs is used. Outputs will be generated by
         // the input selection algorithm based on the content of the [`Burn`] object.
         self.prepare_transaction([], Some(options)).await
     }
+
+    /// Estimates the outcome of a burn, e.g. to show it to the user before burning.
+    ///
+    /// The same input selection as in [`Account::prepare_burn()`] is done, but the selected inputs are unlocked again
+    /// and the prepared transaction is dropped.
+    pub async fn estimate_burn(
+        &self,
+        burn: impl Into<Burn> + Send,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<BurnEstimate> {
+        let burn = burn.into();
+        let prepared_transaction_data = self.prepare_burn(burn.clone(), options).await?;
+        self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+
+        let TransactionEssence::Regular(essence) = &prepared_transaction_data.essence;
+        let output_chain_ids = essence
+            .outputs()
+            .iter()
+            .filter_map(Output::chain_id)
+            .collect::<HashSet<_>>();
+
+        let mut destroyed_outputs = Vec::new();
+        let mut released_storage_deposit = 0;
+        for input in &prepared_transaction_data.inputs_data {
+            if let Some(chain_id) = input.output.chain_id() {
+                if !output_chain_ids.contains(&chain_id.or_from_output_id(input.output_id())) {
+                    destroyed_outputs.push(*input.output_id());
+                    released_storage_deposit += input.output.amount();
+                }
+            }
+        }
+
+        let burned_native_tokens = burn
+            .native_tokens()
+            .iter()
+            .map(|(token_id, amount)| NativeToken::new(*token_id, *amount))
+            .collect::<Result<_, _>>()?;
+
+        Ok(BurnEstimate {
+            destroyed_outputs,
+            released_storage_deposit,
+            burned_native_tokens,
+        })
+    }
+
+    /// Syncs the account and prepares the burn of the full available amount of every native token it holds.
+    ///
+    /// Native tokens whose foundry is controlled by the account are skipped and returned in
+    /// [`PreparedNativeTokensBurn::skipped_token_ids`], since they should be melted instead, unless `force` is set.
+    /// Fails with [`Error::NoNativeTokensToBurn`], holding the skipped token ids, if there is nothing left to burn.
+    pub async fn prepare_burn_all_native_tokens(
+        &self,
+        force: bool,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedNativeTokensBurn> {
+        let balance = self.sync(None).await?;
+        let (burn, skipped_token_ids) = native_tokens_burn(&balance, force);
+
+        if burn.native_tokens().is_empty() {
+            return Err(Error::NoNativeTokensToBurn { skipped_token_ids });
+        }
+
+        Ok(PreparedNativeTokensBurn {
+            prepared_transaction_data: self.prepare_burn(burn, options).await?,
+            skipped_token_ids,
+        })
+    }
+
+    /// Checks that none of the aliases to burn still controls foundries or NFTs which are not burned as well, because
+    /// these could never be unlocked again.
+    async fn check_no_orphaned_outputs(&self, burn: &Burn) -> crate::wallet::Result<()> {
+        if burn.aliases().is_empty() {
+            return Ok(());
+        }
+
+        let account_details = self.details().await;
+
+        for alias_id in burn.aliases() {
+            let alias_address = Address::Alias(AliasAddress::new(*alias_id));
+            let mut foundries = Vec::new();
+            let mut nfts = Vec::new();
+
+            for (output_id, output_data) in account_details.unspent_outputs() {
+                match &output_data.output {
+                    Output::Foundry(foundry)
+                        if foundry.alias_address().alias_id() == alias_id && !burn.foundries().contains(&foundry.id()) =>
+                    {
+                        foundries.push(foundry.id());
+                    }
+                    Output::Nft(nft) => {
+                        let nft_id = nft.nft_id_non_null(output_id);
+                        if nft.address() == &alias_address && !burn.nfts().contains(&nft_id) {
+                            nfts.push(nft_id);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            if !foundries.is_empty() || !nfts.is_empty() {
+                return Err(Error::AliasControlsOutputs {
+                    alias_id: *alias_id,
+                    foundries,
+                    nfts,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the account holds at least the amount of each native token to burn, to fail with a clear error
+    /// instead of an input selection one.
+    async fn check_native_tokens_available(&self, burn: &Burn) -> crate::wallet::Result<()> {
+        if burn.native_tokens().is_empty() {
+            return Ok(());
+        }
+
+        let balance = self.balance().await?;
+
+        for (token_id, required) in burn.native_tokens() {
+            let available = balance
+                .native_tokens()
+                .iter()
+                .find(|native_token| native_token.token_id() == token_id)
+                .map_or_else(U256::zero, |native_token| native_token.available());
+
+            if available < *required {
+                return Err(Error::InsufficientNativeTokens {
+                    token_id: *token_id,
+                    available,
+                    required: *required,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Builds a [`Burn`] of the available amount of every native token in the balance and returns it with the tokens
+/// skipped because their foundry is in the balance too.
+fn native_tokens_burn(balance: &Balance, force: bool) -> (Burn, Vec<TokenId>) {
+    let mut burn = Burn::new();
+    let mut skipped_token_ids = Vec::new();
+
+    for native_token in balance.native_tokens() {
+        if native_token.available().is_zero() {
+            continue;
+        }
+        if !force && balance.foundries().contains(&FoundryId::from(*native_token.token_id())) {
+            skipped_token_ids.push(*native_token.token_id());
+            continue;
+        }
+        burn = burn.add_native_token(*native_token.token_id(), native_token.available());
+    }
+
+    (burn, skipped_token_ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::wallet::account::types::NativeTokensBalance;
+
+    fn balance_with(native_tokens: &[(TokenId, u64)], foundries: Vec<FoundryId>) -> Balance {
+        Balance {
+            native_tokens: native_tokens
+                .iter()
+                .map(|(token_id, amount)| NativeTokensBalance {
+                    token_id: *token_id,
+                    total: U256::from(*amount),
+                    available: U256::from(*amount),
+                    metadata: None,
+                })
+                .collect(),
+            foundries,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn burn_all_native_tokens() {
+        let token_ids = [TokenId::new([1; 38]), TokenId::new([2; 38]), TokenId::new([3; 38])];
+        let balance = balance_with(
+            &[(token_ids[0], 10), (token_ids[1], 20), (token_ids[2], 30)],
+            vec![FoundryId::from(token_ids[1])],
+        );
+
+        let (burn, skipped_token_ids) = native_tokens_burn(&balance, false);
+        assert_eq!(
+            burn.native_tokens().iter().collect::<Vec<_>>(),
+            [(&token_ids[0], &U256::from(10)), (&token_ids[2], &U256::from(30))]
+        );
+        assert_eq!(skipped_token_ids, [token_ids[1]]);
+
+        let (burn, skipped_token_ids) = native_tokens_burn(&balance, true);
+        assert_eq!(
+            burn.native_tokens().iter().collect::<Vec<_>>(),
+            [
+                (&token_ids[0], &U256::from(10)),
+                (&token_ids[1], &U256::from(20)),
+                (&token_ids[2], &U256::from(30))
+            ]
+        );
+        assert!(skipped_token_ids.is_empty());
+    }
 }
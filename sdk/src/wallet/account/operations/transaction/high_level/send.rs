@@ -9,8 +9,10 @@ use crate::{
     types::block::{
         address::Bech32Address,
         output::{
+            feature::{Feature, MetadataFeature, SenderFeature, TagFeature},
             unlock_condition::{
                 AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition,
+                TimelockUnlockCondition,
             },
             BasicOutputBuilder, MinimumStorageDepositBasicOutput,
         },
@@ -24,6 +26,9 @@ use crate::{
     },
 };
 
+// Input selection only needs another attempt if lowering the amount changes which outputs stay in the account.
+const SEND_MAX_ATTEMPTS: usize = 3;
+
 /// Parameters for `send()`
 #[derive(Debug, Clone, Serialize, Deserialize, Getters)]
 pub struct SendParams {
@@ -39,11 +44,39 @@ pub struct SendParams {
     /// default to the first address of the account.
     #[getset(get = "pub")]
     return_address: Option<Bech32Address>,
-    /// Expiration in seconds, after which the output will be available for the sender again, if not spent by the
-    /// receiver already. The expiration will only be used if one is necessary given the provided amount. If an
-    /// expiration is needed but not provided, it will default to one day.
+    /// Expiration in seconds relative to the time of sending, after which the output will be available for the
+    /// sender again, if not spent by the receiver already. The expiration will only be used if one is necessary given
+    /// the provided amount. If an expiration is needed but not provided, it will default to one day.
     #[getset(get = "pub")]
     expiration: Option<u32>,
+    /// Unix timestamp in seconds until which the output can't be unlocked by the receiver, useful for vesting or
+    /// scheduled releases. Unlike `expiration`, which is relative to the time of sending, this is an absolute time.
+    /// It needs to be in the future. If the output also gets an expiration, the expiration needs to be after the
+    /// timelock, otherwise the receiver could never claim it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub")]
+    timelock: Option<u32>,
+    /// Bech32 encoded sender feature, it needs to be an address of the account. Useful to let indexers find the
+    /// outputs sent by an application.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub")]
+    sender: Option<Bech32Address>,
+    /// Metadata feature.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::utils::serde::option_prefix_hex_bytes"
+    )]
+    #[getset(get = "pub")]
+    metadata: Option<Vec<u8>>,
+    /// Tag feature.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::utils::serde::option_prefix_hex_bytes"
+    )]
+    #[getset(get = "pub")]
+    tag: Option<Vec<u8>>,
 }
 
 impl SendParams {
@@ -53,6 +86,10 @@ impl SendParams {
             address: address.convert()?,
             return_address: None,
             expiration: None,
+            timelock: None,
+            sender: None,
+            metadata: None,
+            tag: None,
         })
     }
 
@@ -73,6 +110,40 @@ impl SendParams {
         self.expiration = expiration.into();
         self
     }
+
+    pub fn with_timelock(mut self, timelock: impl Into<Option<u32>>) -> Self {
+        self.timelock = timelock.into();
+        self
+    }
+
+    pub fn try_with_sender(mut self, sender: impl ConvertTo<Bech32Address>) -> Result<Self, crate::wallet::Error> {
+        self.sender = Some(sender.convert()?);
+        Ok(self)
+    }
+
+    pub fn with_sender(mut self, sender: impl Into<Option<Bech32Address>>) -> Self {
+        self.sender = sender.into();
+        self
+    }
+
+    pub fn with_metadata(mut self, metadata: impl Into<Option<Vec<u8>>>) -> Self {
+        self.metadata = metadata.into();
+        self
+    }
+
+    pub fn with_tag(mut self, tag: impl Into<Option<Vec<u8>>>) -> Self {
+        self.tag = tag.into();
+        self
+    }
+}
+
+/// A prepared transaction sending all available base coins, see [`Account::prepare_send_max()`].
+#[derive(Clone, Debug)]
+pub struct PreparedSendMax {
+    /// The prepared transaction.
+    pub prepared_transaction_data: PreparedTransactionData,
+    /// The amount sent to the address.
+    pub amount: u64,
 }
 
 impl<S: 'static + SecretManage> Account<S>
@@ -125,6 +196,44 @@ where
         self.sign_and_submit_transaction(prepared_transaction, options).await
     }
 
+    /// Prepares sending all available base coins to a single address.
+    ///
+    /// Only the storage deposit of the outputs which stay in the account is kept back, e.g. for a remainder holding
+    /// native tokens or for transitioned aliases and NFTs. The amount sent is returned with the prepared transaction.
+    pub async fn prepare_send_max(
+        &self,
+        address: impl ConvertTo<Bech32Address>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedSendMax> {
+        log::debug!("[TRANSACTION] prepare_send_max");
+        let address = address.convert()?;
+        let options = options.into();
+        let mut amount = self.balance().await?.base_coin().available();
+
+        let mut attempt = 1;
+        loop {
+            match self
+                .prepare_send([SendParams::new(amount, address)?], options.clone())
+                .await
+            {
+                Ok(prepared_transaction_data) => {
+                    return Ok(PreparedSendMax {
+                        prepared_transaction_data,
+                        amount,
+                    });
+                }
+                // The missing amount is what's needed for the outputs which stay in the account, so it's kept back
+                Err(Error::InsufficientFunds { available, required })
+                    if attempt < SEND_MAX_ATTEMPTS && amount > required - available =>
+                {
+                    amount -= required - available;
+                    attempt += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
     /// Prepares the transaction for
     /// [Account::send()](crate::wallet::Account::send).
     pub async fn prepare_send<I: IntoIterator<Item = SendParams> + Send>(
@@ -151,26 +260,59 @@ where
             amount,
             return_address,
             expiration,
+            timelock,
+            sender,
+            metadata,
+            tag,
         } in params
         {
             self.client().bech32_hrp_matches(address.hrp()).await?;
             let return_address = return_address
                 .map(|return_address| {
-                    if return_address.hrp() != address.hrp() {
-                        Err(crate::client::Error::Bech32HrpMismatch {
-                            provided: return_address.hrp().to_string(),
-                            expected: address.hrp().to_string(),
-                        })?;
-                    }
+                    return_address
+                        .hrp_matches(address.hrp())
+                        .map_err(crate::client::Error::from)?;
                     Ok::<_, Error>(return_address)
                 })
                 .transpose()?
                 .unwrap_or(default_return_address.address);
 
+            let mut features = Vec::new();
+            if let Some(sender) = sender {
+                self.client().bech32_hrp_matches(sender.hrp()).await?;
+                if !account_addresses
+                    .iter()
+                    .any(|account_address| account_address.address.inner() == sender.inner())
+                {
+                    return Err(Error::AddressNotFoundInAccount(sender));
+                }
+                features.push(Feature::from(SenderFeature::new(sender)));
+            }
+            if let Some(metadata) = metadata {
+                features.push(MetadataFeature::new(metadata)?.into());
+            }
+            if let Some(tag) = tag {
+                features.push(TagFeature::new(tag)?.into());
+            }
+
+            if let Some(timelock) = timelock {
+                if timelock <= local_time {
+                    return Err(Error::InvalidTimelock {
+                        timelock,
+                        local_time,
+                        expiration: None,
+                    });
+                }
+            }
+
             // Get the minimum required amount for an output assuming it does not need a storage deposit.
-            let output = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+            let mut output_builder = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
                 .add_unlock_condition(AddressUnlockCondition::new(address))
-                .finish_output(token_supply)?;
+                .with_features(features.clone());
+            if let Some(timelock) = timelock {
+                output_builder = output_builder.add_unlock_condition(TimelockUnlockCondition::new(timelock)?);
+            }
+            let output = output_builder.finish_output(token_supply)?;
 
             if amount >= output.amount() {
                 outputs.push(
@@ -183,11 +325,25 @@ where
                     local_time + expiration_time
                 });
 
+                if let Some(timelock) = timelock {
+                    if timelock >= expiration_time {
+                        return Err(Error::InvalidTimelock {
+                            timelock,
+                            local_time,
+                            expiration: Some(expiration_time),
+                        });
+                    }
+                }
+
                 // Since it does need a storage deposit, calculate how much that should be
-                let storage_deposit_amount = MinimumStorageDepositBasicOutput::new(rent_structure, token_supply)
+                let mut minimum_storage_deposit = MinimumStorageDepositBasicOutput::new(rent_structure, token_supply)
+                    .with_features(features)
                     .with_storage_deposit_return()?
-                    .with_expiration()?
-                    .finish()?;
+                    .with_expiration()?;
+                if timelock.is_some() {
+                    minimum_storage_deposit = minimum_storage_deposit.with_timelock()?;
+                }
+                let storage_deposit_amount = minimum_storage_deposit.finish()?;
 
                 if !options.as_ref().map(|o| o.allow_micro_amount).unwrap_or_default() {
                     return Err(Error::InsufficientFunds {
@@ -147,12 +147,7 @@ where
             self.client().bech32_hrp_matches(address.hrp()).await?;
             let return_address = return_address
                 .map(|addr| {
-                    if address.hrp() != addr.hrp() {
-                        Err(crate::client::Error::Bech32HrpMismatch {
-                            provided: addr.hrp().to_string(),
-                            expected: address.hrp().to_string(),
-                        })?;
-                    }
+                    addr.hrp_matches(address.hrp()).map_err(crate::client::Error::from)?;
                     Ok::<_, Error>(addr)
                 })
                 .transpose()?
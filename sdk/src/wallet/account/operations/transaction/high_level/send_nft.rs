@@ -8,7 +8,10 @@ use crate::{
     client::{api::PreparedTransactionData, secret::SecretManage},
     types::block::{
         address::Bech32Address,
-        output::{unlock_condition::AddressUnlockCondition, NftId, NftOutputBuilder, Output},
+        output::{
+            unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition},
+            NftId, NftOutputBuilder, Output,
+        },
         ConvertTo,
     },
     wallet::account::{operations::transaction::Transaction, Account, TransactionOptions},
@@ -24,6 +27,18 @@ pub struct SendNftParams {
     /// Nft id
     #[getset(get = "pub")]
     nft_id: NftId,
+    /// Bech32 encoded return address, it needs to be an address of the account. Only used with an `expiration`,
+    /// defaults to the first address of the account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub")]
+    return_address: Option<Bech32Address>,
+    /// Expiration in seconds, after which the NFT will be available for the sender again, if not claimed by the
+    /// receiver already. If set, the NFT output gets a storage deposit return unlock condition for its full amount, so
+    /// the receiver has to return the storage deposit when claiming it, and an expiration unlock condition, both with
+    /// the return address. Useful when the receiver might never claim the NFT.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub")]
+    expiration: Option<u32>,
 }
 
 impl SendNftParams {
@@ -35,8 +50,28 @@ impl SendNftParams {
         Ok(Self {
             address: address.convert()?,
             nft_id: nft_id.convert()?,
+            return_address: None,
+            expiration: None,
         })
     }
+
+    pub fn try_with_return_address(
+        mut self,
+        address: impl ConvertTo<Bech32Address>,
+    ) -> Result<Self, crate::wallet::Error> {
+        self.return_address = Some(address.convert()?);
+        Ok(self)
+    }
+
+    pub fn with_return_address(mut self, address: impl Into<Option<Bech32Address>>) -> Self {
+        self.return_address = address.into();
+        self
+    }
+
+    pub fn with_expiration(mut self, expiration: impl Into<Option<u32>>) -> Self {
+        self.expiration = expiration.into();
+        self
+    }
 }
 
 impl<S: 'static + SecretManage> Account<S>
@@ -88,11 +123,19 @@ where
         log::debug!("[TRANSACTION] prepare_send_nft");
 
         let unspent_outputs = self.unspent_outputs(None).await?;
+        let rent_structure = self.client().get_rent_structure().await?;
         let token_supply = self.client().get_token_supply().await?;
+        let account_addresses = self.addresses().await?;
 
         let mut outputs = Vec::new();
 
-        for SendNftParams { address, nft_id } in params {
+        for SendNftParams {
+            address,
+            nft_id,
+            return_address,
+            expiration,
+        } in params
+        {
             self.client().bech32_hrp_matches(address.hrp()).await?;
 
             // Find nft output from the inputs
@@ -105,9 +148,47 @@ where
             }) {
                 if let Output::Nft(nft_output) = &nft_output_data.output {
                     // Set the nft id and new address unlock condition
-                    let nft_builder = NftOutputBuilder::from(nft_output)
+                    let mut nft_builder = NftOutputBuilder::from(nft_output)
                         .with_nft_id(nft_id)
                         .with_unlock_conditions([AddressUnlockCondition::new(address)]);
+
+                    if let Some(expiration) = expiration {
+                        let return_address = match return_address {
+                            Some(return_address) => {
+                                if !account_addresses.iter().any(|a| a.address == return_address) {
+                                    return Err(crate::wallet::Error::AddressNotFoundInAccount(return_address));
+                                }
+                                return_address
+                            }
+                            None => {
+                                account_addresses
+                                    .first()
+                                    .ok_or(crate::wallet::Error::FailedToGetRemainder)?
+                                    .address
+                            }
+                        };
+                        let expiration_time = self.client().get_time_checked().await? + expiration;
+
+                        nft_builder = nft_builder
+                            .add_unlock_condition(StorageDepositReturnUnlockCondition::new(
+                                return_address,
+                                nft_output.amount(),
+                                token_supply,
+                            )?)
+                            .add_unlock_condition(ExpirationUnlockCondition::new(return_address, expiration_time)?);
+
+                        // The additional unlock conditions can increase the required storage deposit
+                        let minimum_amount = nft_builder
+                            .clone()
+                            .with_minimum_storage_deposit(rent_structure)
+                            .finish_output(token_supply)?
+                            .amount();
+                        let amount = nft_output.amount().max(minimum_amount);
+                        nft_builder = nft_builder.with_amount(amount).replace_unlock_condition(
+                            StorageDepositReturnUnlockCondition::new(return_address, amount, token_supply)?,
+                        );
+                    }
+
                     outputs.push(nft_builder.finish_output(token_supply)?);
                 }
             } else {
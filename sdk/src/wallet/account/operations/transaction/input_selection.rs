@@ -12,7 +12,7 @@ use crate::{
     },
     types::block::{
         address::Address,
-        output::{Output, OutputId},
+        output::{Feature, Output, OutputId},
     },
     wallet::account::{
         operations::helpers::time::can_output_be_unlocked_forever_from_now_on, Account, AccountDetails, OutputData,
@@ -24,6 +24,7 @@ where
     crate::wallet::Error: From<S::Error>,
 {
     /// Selects inputs for a transaction and locks them in the account, so they don't get used again
+    #[allow(clippy::too_many_arguments)]
     pub(crate) async fn select_inputs(
         &self,
         outputs: Vec<Output>,
@@ -31,6 +32,8 @@ where
         mandatory_inputs: Option<HashSet<OutputId>>,
         remainder_address: Option<Address>,
         burn: Option<&Burn>,
+        allow_additional_input_selection: bool,
+        remainder_features: Option<&[Feature]>,
     ) -> crate::wallet::Result<Selected> {
         log::debug!("[TRANSACTION] select_inputs");
         // Voting output needs to be requested before to prevent a deadlock
@@ -49,7 +52,11 @@ where
 
         let current_time = self.client().get_time_checked().await?;
         #[allow(unused_mut)]
-        let mut forbidden_inputs = account_details.locked_outputs.clone();
+        let mut forbidden_inputs = account_details
+            .locked_outputs
+            .union(&account_details.frozen_outputs)
+            .copied()
+            .collect::<HashSet<_>>();
 
         let addresses = account_details
             .public_addresses()
@@ -81,6 +88,29 @@ where
             mandatory_inputs.as_ref(),
         )?;
 
+        // Only keep the explicitly provided inputs, so input selection can't add any other
+        let available_outputs_signing_data = if allow_additional_input_selection {
+            available_outputs_signing_data
+        } else {
+            if custom_inputs.is_none() && mandatory_inputs.is_none() {
+                return Err(crate::wallet::Error::CustomInput(
+                    "additional input selection is disallowed, but no custom or mandatory inputs were provided"
+                        .to_string(),
+                ));
+            }
+            available_outputs_signing_data
+                .into_iter()
+                .filter(|input| {
+                    custom_inputs
+                        .as_ref()
+                        .is_some_and(|inputs| inputs.contains(input.output_id()))
+                        || mandatory_inputs
+                            .as_ref()
+                            .is_some_and(|inputs| inputs.contains(input.output_id()))
+                })
+                .collect()
+        };
+
         // if custom inputs are provided we should only use them (validate if we have the outputs in this account and
         // that the amount is enough)
         if let Some(custom_inputs) = custom_inputs {
@@ -91,6 +121,11 @@ where
                         "provided custom input {input} is already used in another transaction",
                     )));
                 }
+                if account_details.frozen_outputs.contains(input) {
+                    return Err(crate::wallet::Error::CustomInput(format!(
+                        "provided custom input {input} is frozen",
+                    )));
+                }
             }
 
             let mut input_selection = InputSelection::new(
@@ -106,6 +141,10 @@ where
                 input_selection = input_selection.remainder_address(address);
             }
 
+            if let Some(features) = remainder_features {
+                input_selection = input_selection.remainder_features(features.to_vec());
+            }
+
             if let Some(burn) = burn {
                 input_selection = input_selection.burn(burn.clone());
             }
@@ -126,6 +165,11 @@ where
                         "provided custom input {input} is already used in another transaction",
                     )));
                 }
+                if account_details.frozen_outputs.contains(input) {
+                    return Err(crate::wallet::Error::CustomInput(format!(
+                        "provided custom input {input} is frozen",
+                    )));
+                }
             }
 
             let mut input_selection = InputSelection::new(
@@ -141,6 +185,10 @@ where
                 input_selection = input_selection.remainder_address(address);
             }
 
+            if let Some(features) = remainder_features {
+                input_selection = input_selection.remainder_features(features.to_vec());
+            }
+
             if let Some(burn) = burn {
                 input_selection = input_selection.burn(burn.clone());
             }
@@ -172,6 +220,10 @@ where
             input_selection = input_selection.remainder_address(address);
         }
 
+        if let Some(features) = remainder_features {
+            input_selection = input_selection.remainder_features(features.to_vec());
+        }
+
         if let Some(burn) = burn {
             input_selection = input_selection.burn(burn.clone());
         }
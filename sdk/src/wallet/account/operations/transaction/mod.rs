@@ -181,7 +181,7 @@ where
             payload: signed_transaction_data.transaction_payload,
             block_id,
             network_id,
-            timestamp: crate::utils::unix_timestamp_now().as_millis(),
+            timestamp: self.client().unix_timestamp_now().as_millis(),
             inclusion_state: InclusionState::Pending,
             incoming: false,
             note: options.and_then(|o| o.note),
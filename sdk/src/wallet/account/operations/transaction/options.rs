@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     client::api::input_selection::{Burn, BurnDto},
     types::block::{
-        output::OutputId,
+        output::{feature::dto::FeatureDto, Feature, OutputId},
         payload::{dto::TaggedDataPayloadDto, tagged_data::TaggedDataPayload},
         Error,
     },
@@ -14,7 +14,7 @@ use crate::{
 };
 
 /// Options for transactions
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct TransactionOptions {
     pub remainder_value_strategy: RemainderValueStrategy,
     pub tagged_data_payload: Option<TaggedDataPayload>,
@@ -25,6 +25,37 @@ pub struct TransactionOptions {
     pub burn: Option<Burn>,
     pub note: Option<String>,
     pub allow_micro_amount: bool,
+    /// Burning an alias that still controls foundries or NFTs makes them unspendable forever, so it's rejected unless
+    /// this is set.
+    pub allow_orphaning: bool,
+    /// If false, input selection can only use the `custom_inputs` and `mandatory_inputs` and fails if they don't
+    /// cover the transaction.
+    pub allow_additional_input_selection: bool,
+    /// If set, the transaction is checked against the
+    /// [`SignerCapabilities`](crate::client::secret::SignerCapabilities) of the secret manager, so it fails with
+    /// [`Error::BlindSigningRequired`](crate::wallet::Error::BlindSigningRequired) before a Ledger Nano device without
+    /// blind signing is asked to sign it.
+    pub check_signer_capabilities: bool,
+    /// Features to add to the remainder output, only metadata and tag features are supported.
+    pub remainder_features: Option<Vec<Feature>>,
+}
+
+impl Default for TransactionOptions {
+    fn default() -> Self {
+        Self {
+            remainder_value_strategy: Default::default(),
+            tagged_data_payload: None,
+            custom_inputs: None,
+            mandatory_inputs: None,
+            burn: None,
+            note: None,
+            allow_micro_amount: false,
+            allow_orphaning: false,
+            allow_additional_input_selection: true,
+            check_signer_capabilities: false,
+            remainder_features: None,
+        }
+    }
 }
 
 impl TransactionOptions {
@@ -38,12 +69,19 @@ impl TransactionOptions {
             burn: value.burn.map(Burn::try_from).transpose()?,
             note: value.note,
             allow_micro_amount: value.allow_micro_amount,
+            allow_orphaning: value.allow_orphaning,
+            allow_additional_input_selection: value.allow_additional_input_selection,
+            check_signer_capabilities: value.check_signer_capabilities,
+            remainder_features: value
+                .remainder_features
+                .map(|features| features.into_iter().map(Feature::try_from).collect())
+                .transpose()?,
         })
     }
 }
 
 /// Dto for transaction options
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionOptionsDto {
     #[serde(default)]
@@ -60,6 +98,36 @@ pub struct TransactionOptionsDto {
     pub note: Option<String>,
     #[serde(default)]
     pub allow_micro_amount: bool,
+    #[serde(default)]
+    pub allow_orphaning: bool,
+    #[serde(default = "default_true")]
+    pub allow_additional_input_selection: bool,
+    #[serde(default)]
+    pub check_signer_capabilities: bool,
+    #[serde(default)]
+    pub remainder_features: Option<Vec<FeatureDto>>,
+}
+
+impl Default for TransactionOptionsDto {
+    fn default() -> Self {
+        Self {
+            remainder_value_strategy: Default::default(),
+            tagged_data_payload: None,
+            custom_inputs: None,
+            mandatory_inputs: None,
+            burn: None,
+            note: None,
+            allow_micro_amount: false,
+            allow_orphaning: false,
+            allow_additional_input_selection: true,
+            check_signer_capabilities: false,
+            remainder_features: None,
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
 }
 
 #[allow(clippy::enum_variant_names)]
@@ -6,6 +6,8 @@ use std::collections::HashSet;
 use instant::Instant;
 use packable::bounded::TryIntoBoundedU16Error;
 
+#[cfg(feature = "ledger_nano")]
+use crate::client::secret::ledger_nano::needs_blind_signing;
 #[cfg(feature = "events")]
 use crate::wallet::events::types::{AddressData, TransactionProgressEvent, WalletEvent};
 use crate::{
@@ -46,7 +48,7 @@ where
 
         // Validate the number of outputs. The validation shouldn't be performed if [`Burn`] is present.
         // The outputs will be generated by the input selection algorithm (ISA).
-        if !OUTPUT_COUNT_RANGE.contains(&(outputs.len() as u16)) && !is_burn_present {
+        if !u16::try_from(outputs.len()).is_ok_and(|count| OUTPUT_COUNT_RANGE.contains(&count)) && !is_burn_present {
             return Err(crate::types::block::Error::InvalidOutputCount(
                 TryIntoBoundedU16Error::Truncated(outputs.len()),
             ))?;
@@ -54,7 +56,7 @@ where
 
         if let Some(custom_inputs) = options.as_ref().and_then(|options| options.custom_inputs.as_ref()) {
             // validate inputs amount
-            if !INPUT_COUNT_RANGE.contains(&(custom_inputs.len() as u16)) {
+            if !u16::try_from(custom_inputs.len()).is_ok_and(|count| INPUT_COUNT_RANGE.contains(&count)) {
                 return Err(crate::types::block::Error::InvalidInputCount(
                     TryIntoBoundedU16Error::Truncated(custom_inputs.len()),
                 ))?;
@@ -63,7 +65,7 @@ where
 
         if let Some(mandatory_inputs) = options.as_ref().and_then(|options| options.mandatory_inputs.as_ref()) {
             // validate inputs amount
-            if !INPUT_COUNT_RANGE.contains(&(mandatory_inputs.len() as u16)) {
+            if !u16::try_from(mandatory_inputs.len()).is_ok_and(|count| INPUT_COUNT_RANGE.contains(&count)) {
                 return Err(crate::types::block::Error::InvalidInputCount(
                     TryIntoBoundedU16Error::Truncated(mandatory_inputs.len()),
                 ))?;
@@ -100,6 +102,17 @@ where
             None => None,
         };
 
+        // Only asked for if requested, since it can require access to a device
+        #[cfg(feature = "ledger_nano")]
+        let signer_capabilities = if options
+            .as_ref()
+            .is_some_and(|options| options.check_signer_capabilities)
+        {
+            Some(self.wallet.secret_manager.read().await.capabilities().await?)
+        } else {
+            None
+        };
+
         let selected_transaction_data = self
             .select_inputs(
                 outputs,
@@ -113,6 +126,12 @@ where
                     .map(|inputs| HashSet::from_iter(inputs.clone())),
                 remainder_address,
                 options.as_ref().and_then(|options| options.burn.as_ref()),
+                options
+                    .as_ref()
+                    .is_none_or(|options| options.allow_additional_input_selection),
+                options
+                    .as_ref()
+                    .and_then(|options| options.remainder_features.as_deref()),
             )
             .await?;
 
@@ -128,6 +147,17 @@ where
             }
         };
 
+        // Fail before a Ledger Nano device is asked to sign a transaction it would reject
+        #[cfg(feature = "ledger_nano")]
+        if let Some(signer_capabilities) = signer_capabilities.filter(|capabilities| !capabilities.blind_signing) {
+            if let Some(buffer_size) = signer_capabilities.buffer_size {
+                if needs_blind_signing(&prepared_transaction_data, buffer_size) {
+                    self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+                    return Err(crate::wallet::Error::BlindSigningRequired);
+                }
+            }
+        }
+
         log::debug!(
             "[TRANSACTION] finished prepare_transaction in {:.2?}",
             prepare_transaction_start_time.elapsed()
@@ -74,12 +74,14 @@ where
             }
         }
 
+        // Evaluate time locks and expirations at the same time as the input selection
+        let current_time = self.client().unix_timestamp_now().as_secs() as u32;
         let unlocks = match self
             .wallet
             .secret_manager
             .read()
             .await
-            .sign_transaction_essence(prepared_transaction_data, None)
+            .sign_transaction_essence(prepared_transaction_data, Some(current_time))
             .await
         {
             Ok(res) => res,
@@ -4,7 +4,7 @@
 #[cfg(feature = "events")]
 use crate::wallet::events::types::{TransactionProgressEvent, WalletEvent};
 use crate::{
-    client::secret::SecretManage,
+    client::{error::PostBlockError, secret::SecretManage},
     types::block::{payload::Payload, BlockId},
     wallet::account::{operations::transaction::TransactionPayload, Account},
 };
@@ -43,7 +43,7 @@ where
             WalletEvent::TransactionProgress(TransactionProgressEvent::Broadcasting),
         )
         .await;
-        let block_id = self.client().post_block(&block).await?;
+        let block_id = self.client().post_block(&block).await.map_err(PostBlockError::from)?;
         log::debug!("[TRANSACTION] submitted block {}", block_id);
         Ok(block_id)
     }
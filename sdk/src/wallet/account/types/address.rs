@@ -8,7 +8,11 @@ use serde::{Deserialize, Serialize};
 
 use crate::types::{
     self,
-    block::{address::Bech32Address, output::OutputId, ConvertTo},
+    block::{
+        address::{Address, Bech32Address},
+        output::OutputId,
+        ConvertTo,
+    },
 };
 
 /// An account address.
@@ -45,6 +49,30 @@ impl ConvertTo<Bech32Address> for AccountAddress {
     }
 }
 
+impl From<AccountAddress> for Bech32Address {
+    fn from(value: AccountAddress) -> Self {
+        value.address
+    }
+}
+
+impl From<&AccountAddress> for Bech32Address {
+    fn from(value: &AccountAddress) -> Self {
+        value.address
+    }
+}
+
+impl From<AccountAddress> for Address {
+    fn from(value: AccountAddress) -> Self {
+        value.address.into_inner()
+    }
+}
+
+impl From<&AccountAddress> for Address {
+    fn from(value: &AccountAddress) -> Self {
+        value.address.into_inner()
+    }
+}
+
 /// An account address with unspent output_ids for unspent outputs.
 #[derive(Debug, Getters, Setters, Clone, Serialize, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -67,3 +95,32 @@ impl AddressWithUnspentOutputs {
         self.address
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use super::*;
+    use crate::types::block::address::{Hrp, ToBech32Ext};
+
+    #[test]
+    fn account_address_conversions() {
+        let bech32_address =
+            Bech32Address::from_str("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy").unwrap();
+        let account_address = AccountAddress {
+            address: bech32_address,
+            key_index: 0,
+            internal: false,
+            used: false,
+        };
+
+        assert_eq!(Bech32Address::from(&account_address), bech32_address);
+        assert_eq!(Address::from(&account_address), bech32_address.into_inner());
+        assert_eq!(
+            (&account_address).to_bech32(Hrp::from_str_unchecked("smr")),
+            bech32_address.into_inner().to_bech32(Hrp::from_str_unchecked("smr"))
+        );
+        assert_eq!(Address::from(account_address.clone()), bech32_address.into_inner());
+        assert_eq!(Bech32Address::from(account_address), bech32_address);
+    }
+}
@@ -34,6 +34,33 @@ where
         Ok(())
     }
 
+    /// Freeze an unspent output, so it isn't used as input for transactions, not even as custom or mandatory input,
+    /// and isn't claimed. Frozen outputs are still part of the total balance, but not of the available one. The output
+    /// is unfrozen again with [`Account::unfreeze_output()`](crate::wallet::Account::unfreeze_output) or when it gets
+    /// spent.
+    pub async fn freeze_output(&self, output_id: OutputId) -> crate::wallet::Result<()> {
+        let mut account_details = self.details_mut().await;
+        if !account_details.unspent_outputs.contains_key(&output_id) {
+            return Err(crate::wallet::Error::OutputNotFoundInUnspentOutputs(output_id));
+        }
+        if account_details.frozen_outputs.insert(output_id) {
+            #[cfg(feature = "storage")]
+            self.save(Some(&account_details)).await?;
+        }
+        Ok(())
+    }
+
+    /// Unfreeze an output frozen with [`Account::freeze_output()`](crate::wallet::Account::freeze_output), so it can be
+    /// used as input for transactions again.
+    pub async fn unfreeze_output(&self, output_id: &OutputId) -> crate::wallet::Result<()> {
+        let mut account_details = self.details_mut().await;
+        if account_details.frozen_outputs.remove(output_id) {
+            #[cfg(feature = "storage")]
+            self.save(Some(&account_details)).await?;
+        }
+        Ok(())
+    }
+
     /// Update account with newly synced data and emit events for outputs
     pub(crate) async fn update_account(
         &self,
@@ -117,6 +144,7 @@ where
                 if output.network_id == network_id {
                     log::debug!("[SYNC] Spent output {}", output_id);
                     account_details.locked_outputs.remove(&output_id);
+                    account_details.frozen_outputs.remove(&output_id);
                     account_details.unspent_outputs.remove(&output_id);
                     // Update spent data fields
                     if let Some(output_data) = account_details.outputs.get_mut(&output_id) {
@@ -167,10 +195,20 @@ where
                     .await;
                 }
             };
-            if !output_data.is_spent {
+            if output_data.is_spent {
+                continue;
+            }
+            if options
+                .amount_range
+                .as_ref()
+                .is_none_or(|range| range.contains(&output_data.output.amount()))
+            {
                 account_details
                     .unspent_outputs
                     .insert(output_data.output_id, output_data);
+            } else {
+                log::debug!("[SYNC] Output {} is outside of the amount range", output_data.output_id);
+                account_details.unspent_outputs.remove(&output_data.output_id);
             }
         }
 
@@ -180,7 +218,7 @@ where
                 "[SYNC] storing account {} with new synced data",
                 account_details.alias()
             );
-            self.save(Some(&account_details)).await?;
+            self.save_batched(&account_details).await?;
         }
         Ok(())
     }
@@ -247,7 +285,7 @@ where
                 "[SYNC] storing account {} with new synced transactions",
                 account_details.alias()
             );
-            self.save(Some(&account_details)).await?;
+            self.save_batched(&account_details).await?;
         }
         Ok(())
     }
@@ -272,7 +310,7 @@ where
         #[cfg(feature = "storage")]
         {
             log::debug!("[update_account_addresses] storing account {}", account_details.index());
-            self.save(Some(&account_details)).await?;
+            self.save_batched(&account_details).await?;
         }
         Ok(())
     }
@@ -301,7 +339,7 @@ where
                 "[SYNC] storing account {} after updating it with new bech32 hrp",
                 account_details.alias()
             );
-            self.save(Some(&account_details)).await?;
+            self.save_batched(&account_details).await?;
         }
 
         Ok(())
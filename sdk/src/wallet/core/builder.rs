@@ -142,6 +142,8 @@ where
 
         #[cfg(feature = "storage")]
         let mut storage_manager = StorageManager::new(storage, storage_options.encryption_key.clone()).await?;
+        #[cfg(feature = "storage")]
+        storage_manager.set_write_policy(storage_options.write_policy);
 
         #[cfg(feature = "storage")]
         let read_manager_builder = Self::load(&storage_manager).await?;
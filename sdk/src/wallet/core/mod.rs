@@ -4,6 +4,8 @@
 pub(crate) mod builder;
 pub(crate) mod operations;
 
+#[cfg(feature = "events")]
+use std::sync::Weak;
 use std::sync::{
     atomic::{AtomicU32, AtomicUsize},
     Arc,
@@ -16,7 +18,7 @@ pub use self::builder::WalletBuilder;
 #[cfg(feature = "events")]
 use crate::wallet::events::{
     types::{Event, WalletEventType},
-    EventEmitter,
+    EventEmitter, ListenerId, SUBSCRIPTION_BUFFER_SIZE,
 };
 #[cfg(feature = "storage")]
 use crate::wallet::storage::{StorageManager, StorageOptions};
@@ -68,6 +70,59 @@ where
         log::debug!("creating account");
         AccountBuilder::<S>::new(self.clone())
     }
+
+    /// Subscribe to wallet events, empty vec will subscribe to all events. The events are yielded by the returned
+    /// stream in the order they were emitted. Up to 1000 events are buffered, further events are dropped until the
+    /// stream catches up. The underlying listener is removed once the stream is dropped.
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    pub async fn subscribe<I: IntoIterator<Item = WalletEventType> + Send>(
+        &self,
+        events: I,
+    ) -> impl futures::Stream<Item = Event>
+    where
+        I::IntoIter: Send,
+    {
+        let (sender, receiver) = tokio::sync::mpsc::channel(SUBSCRIPTION_BUFFER_SIZE);
+        let listener_id = self.event_emitter.write().await.on(events, move |event| {
+            if let Err(tokio::sync::mpsc::error::TrySendError::Full(_)) = sender.try_send(event.clone()) {
+                log::warn!("[subscribe] subscription buffer full, dropping event");
+            }
+        });
+        let guard = ListenerGuard {
+            wallet: Arc::downgrade(&self.inner),
+            listener_id,
+        };
+
+        futures::stream::unfold((receiver, guard), |(mut receiver, guard)| async move {
+            receiver.recv().await.map(|event| (event, (receiver, guard)))
+        })
+    }
+}
+
+// Removes the listener of a subscription once its stream is dropped.
+#[cfg(feature = "events")]
+struct ListenerGuard<S: 'static + SecretManage> {
+    wallet: Weak<WalletInner<S>>,
+    listener_id: ListenerId,
+}
+
+#[cfg(feature = "events")]
+impl<S: 'static + SecretManage> Drop for ListenerGuard<S> {
+    fn drop(&mut self) {
+        let Some(wallet) = self.wallet.upgrade() else {
+            return;
+        };
+        let listener_id = self.listener_id;
+        if let Ok(mut emitter) = wallet.event_emitter.try_write() {
+            emitter.remove(listener_id);
+            return;
+        }
+        // The lock is held by someone else and can only be awaited asynchronously, so the listener is removed in a task
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            runtime.spawn(async move { wallet.event_emitter.write().await.remove(listener_id) });
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -154,7 +209,7 @@ where
         Ok(balance)
     }
 
-    /// Sync all accounts
+    /// Sync all accounts, see [`Account::sync()`](crate::wallet::Account::sync) for calling it from multiple tasks.
     pub async fn sync(&self, options: Option<SyncOptions>) -> crate::wallet::Result<Balance> {
         let mut balance = Balance::default();
 
@@ -164,6 +219,14 @@ where
 
         Ok(balance)
     }
+
+    /// Writes all account changes batched by the [`StorageWritePolicy`](crate::wallet::storage::StorageWritePolicy)
+    /// and flushes the storage.
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    pub async fn flush_storage(&self) -> crate::wallet::Result<()> {
+        self.storage_manager.write().await.flush_pending().await
+    }
 }
 
 impl<S: SecretManage> WalletInner<S> {
@@ -224,3 +287,65 @@ impl<S: SecretManage> Drop for Wallet<S> {
         log::debug!("drop Wallet");
     }
 }
+
+#[cfg(test)]
+impl Wallet {
+    /// Creates a wallet with a random mnemonic for unit tests. With storage its data is written to
+    /// `test-storage/<name>`, which is cleared first and can be removed with [`Wallet::remove_test_storage()`].
+    #[allow(unused_variables)]
+    pub(crate) async fn for_test(name: &str, node_url: &str) -> Self {
+        let secret_manager = crate::client::secret::mnemonic::MnemonicSecretManager::try_from_mnemonic(
+            Client::generate_mnemonic().unwrap(),
+        )
+        .unwrap();
+
+        #[allow(unused_mut)]
+        let mut wallet_builder = Self::builder()
+            .with_secret_manager(SecretManager::Mnemonic(secret_manager))
+            .with_client_options(crate::wallet::ClientOptions::new().with_node(node_url).unwrap())
+            .with_coin_type(crate::client::constants::SHIMMER_COIN_TYPE);
+        #[cfg(feature = "storage")]
+        {
+            Self::remove_test_storage(name);
+            wallet_builder = wallet_builder.with_storage_path(format!("test-storage/{name}"));
+        }
+
+        wallet_builder.finish().await.unwrap()
+    }
+
+    /// Removes the storage of a wallet created with [`Wallet::for_test()`].
+    #[allow(unused_variables)]
+    pub(crate) fn remove_test_storage(name: &str) {
+        #[cfg(feature = "storage")]
+        std::fs::remove_dir_all(format!("test-storage/{name}")).ok();
+    }
+}
+
+#[cfg(all(test, feature = "events"))]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::wallet::events::types::WalletEvent;
+
+    #[tokio::test]
+    async fn subscription_listener_removed_on_drop() {
+        let wallet = Wallet::for_test("subscription_listener_removed_on_drop", "http://127.0.0.1:0").await;
+
+        let events = wallet.subscribe([]).await;
+        assert!(!wallet.event_emitter.read().await.is_empty());
+
+        // Events emitted while the stream isn't polled are buffered
+        wallet.emit_test_event(WalletEvent::ConsolidationRequired).await;
+        futures::pin_mut!(events);
+        assert_eq!(
+            futures::StreamExt::next(&mut events).await.unwrap().event,
+            WalletEvent::ConsolidationRequired
+        );
+
+        drop(events);
+        assert!(wallet.event_emitter.read().await.is_empty());
+
+        Wallet::remove_test_storage("subscription_listener_removed_on_drop");
+    }
+}
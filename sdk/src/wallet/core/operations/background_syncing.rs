@@ -3,11 +3,15 @@
 
 use std::{sync::atomic::Ordering, time::Duration};
 
+use futures::Stream;
 use tokio::time::sleep;
 
 use crate::{
     client::secret::SecretManage,
-    wallet::{account::operations::syncing::SyncOptions, Wallet},
+    wallet::{
+        account::{operations::syncing::SyncOptions, types::Balance},
+        Wallet,
+    },
 };
 
 /// The default interval for background syncing
@@ -95,4 +99,40 @@ where
         }
         Ok(())
     }
+
+    /// Returns a stream which syncs all accounts every `interval` and yields the balance whenever it changed. The first
+    /// balance is yielded after the initial sync; sync errors are yielded too, without ending the stream.
+    pub fn balance_stream(
+        &self,
+        options: Option<SyncOptions>,
+        interval: Duration,
+    ) -> impl Stream<Item = crate::wallet::Result<Balance>> {
+        futures::stream::unfold(
+            (self.clone(), None::<Balance>, false),
+            move |(wallet, mut last_balance, mut started)| {
+                let options = options.clone();
+                async move {
+                    loop {
+                        if started {
+                            #[cfg(target_family = "wasm")]
+                            gloo_timers::future::sleep(interval).await;
+                            #[cfg(not(target_family = "wasm"))]
+                            sleep(interval).await;
+                        }
+                        started = true;
+
+                        match wallet.sync(options.clone()).await {
+                            // Don't yield unchanged balances
+                            Ok(balance) if last_balance.as_ref() == Some(&balance) => continue,
+                            Ok(balance) => {
+                                last_balance.replace(balance.clone());
+                                return Some((Ok(balance), (wallet, last_balance, started)));
+                            }
+                            Err(err) => return Some((Err(err), (wallet, last_balance, started))),
+                        }
+                    }
+                }
+            },
+        )
+    }
 }
@@ -0,0 +1,74 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(feature = "stronghold")]
+use crate::client::{secret::SecretManager, stronghold::StrongholdAdapter};
+use crate::{client::secret::SecretManage, wallet::Wallet};
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Gracefully shuts the wallet down.
+    ///
+    /// Stops the background syncing, persists all accounts (including their pending transactions), flushes the
+    /// storage and, if the wallet uses Stronghold, clears the key so the snapshot is written and unloaded.
+    ///
+    /// Clones of the wallet stay usable afterwards: background syncing stays stopped until it's started again and the
+    /// Stronghold password needs to be set again before signing. Shutting down a clone again is harmless.
+    pub async fn shutdown(self) -> crate::wallet::Result<()> {
+        log::debug!("[shutdown]");
+        self.stop_background_syncing().await?;
+
+        #[cfg(feature = "storage")]
+        {
+            for account in self.accounts.read().await.iter() {
+                account.save(None).await?;
+            }
+            self.flush_storage().await?;
+        }
+
+        #[cfg(feature = "stronghold")]
+        {
+            let secret_manager = self.secret_manager.read().await;
+            let secret_manager: &dyn std::any::Any = &*secret_manager;
+
+            if let Some(SecretManager::Stronghold(stronghold)) = secret_manager.downcast_ref::<SecretManager>() {
+                stronghold.clear_key().await;
+            } else if let Some(stronghold) = secret_manager.downcast_ref::<StrongholdAdapter>() {
+                stronghold.clear_key().await;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::atomic::Ordering, time::Duration};
+
+    use crate::wallet::Wallet;
+
+    #[ignore]
+    #[tokio::test]
+    async fn shutdown_stops_background_syncing() {
+        let wallet = Wallet::for_test("shutdown_stops_background_syncing", "http://localhost:14265").await;
+
+        wallet
+            .start_background_syncing(None, Some(Duration::from_secs(1)))
+            .await
+            .unwrap();
+        assert_eq!(wallet.background_syncing_status.load(Ordering::Relaxed), 1);
+
+        wallet.clone().shutdown().await.unwrap();
+        assert_eq!(wallet.background_syncing_status.load(Ordering::Relaxed), 0);
+
+        // The remaining clone can still be used and shut down again
+        wallet.stop_background_syncing().await.unwrap();
+        assert!(wallet.get_accounts().await.unwrap().is_empty());
+        wallet.shutdown().await.unwrap();
+
+        Wallet::remove_test_storage("shutdown_stops_background_syncing");
+    }
+}
@@ -1,9 +1,10 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::time::Duration;
+use std::{future::Future, sync::Arc, time::Duration};
 
 use crypto::keys::bip39::Mnemonic;
+use tokio::sync::RwLock;
 
 use crate::{
     client::{secret::SecretManager, stronghold::StrongholdAdapter, utils::Password},
@@ -81,6 +82,25 @@ impl Wallet {
             Err(crate::client::Error::SecretManagerMismatch.into())
         }
     }
+
+    /// Unlocks the Stronghold, runs `f` while the key is held and clears the password afterwards.
+    ///
+    /// The password clear interval is suspended while `f` runs, so the key stays available for the whole batch of
+    /// operations. The password is cleared and the previous interval restored once `f` completes, regardless of its
+    /// result, and also if `f` panics or the returned future is dropped.
+    pub async fn with_unlocked_stronghold<F, Fut, R>(
+        &self,
+        password: impl Into<Password> + Send,
+        f: F,
+    ) -> crate::wallet::Result<R>
+    where
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = crate::wallet::Result<R>> + Send,
+        R: Send,
+    {
+        log::debug!("[with_unlocked_stronghold]");
+        with_unlocked_stronghold(&self.secret_manager, password.into(), f).await
+    }
 }
 
 impl Wallet<StrongholdAdapter> {
@@ -124,4 +144,109 @@ impl Wallet<StrongholdAdapter> {
         log::debug!("[is_stronghold_password_available]");
         Ok(self.secret_manager.write().await.is_key_available().await)
     }
+
+    /// Unlocks the Stronghold, runs `f` while the key is held and clears the password afterwards.
+    ///
+    /// The password clear interval is suspended while `f` runs, so the key stays available for the whole batch of
+    /// operations. The password is cleared and the previous interval restored once `f` completes, regardless of its
+    /// result, and also if `f` panics or the returned future is dropped.
+    pub async fn with_unlocked_stronghold<F, Fut, R>(
+        &self,
+        password: impl Into<Password> + Send,
+        f: F,
+    ) -> crate::wallet::Result<R>
+    where
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = crate::wallet::Result<R>> + Send,
+        R: Send,
+    {
+        log::debug!("[with_unlocked_stronghold]");
+        with_unlocked_stronghold(&self.secret_manager, password.into(), f).await
+    }
+}
+
+/// Secret managers that can hold a Stronghold.
+trait AsStronghold: 'static + Send + Sync {
+    fn as_stronghold(&mut self) -> Option<&mut StrongholdAdapter>;
+}
+
+impl AsStronghold for SecretManager {
+    fn as_stronghold(&mut self) -> Option<&mut StrongholdAdapter> {
+        match self {
+            Self::Stronghold(stronghold) => Some(stronghold),
+            _ => None,
+        }
+    }
+}
+
+impl AsStronghold for StrongholdAdapter {
+    fn as_stronghold(&mut self) -> Option<&mut StrongholdAdapter> {
+        Some(self)
+    }
+}
+
+async fn with_unlocked_stronghold<S, F, Fut, R>(
+    secret_manager: &Arc<RwLock<S>>,
+    password: Password,
+    f: F,
+) -> crate::wallet::Result<R>
+where
+    S: AsStronghold,
+    F: FnOnce() -> Fut + Send,
+    Fut: Future<Output = crate::wallet::Result<R>> + Send,
+    R: Send,
+{
+    let timeout = {
+        let secret_manager = &mut *secret_manager.write().await;
+        let stronghold = secret_manager
+            .as_stronghold()
+            .ok_or(crate::client::Error::SecretManagerMismatch)?;
+        stronghold.set_password(password).await?;
+        let timeout = stronghold.get_timeout();
+        stronghold.set_timeout(None).await;
+        timeout
+    };
+    let guard = UnlockedStrongholdGuard {
+        secret_manager: secret_manager.clone(),
+        timeout,
+        locked: false,
+    };
+
+    let result = f().await;
+
+    guard.lock().await;
+    result
+}
+
+/// Clears the key of an unlocked Stronghold and restores its password clear interval when it's dropped, so the
+/// Stronghold doesn't stay unlocked if the operations panic or their future is dropped.
+struct UnlockedStrongholdGuard<S: AsStronghold> {
+    secret_manager: Arc<RwLock<S>>,
+    timeout: Option<Duration>,
+    locked: bool,
+}
+
+impl<S: AsStronghold> UnlockedStrongholdGuard<S> {
+    async fn lock(mut self) {
+        lock_stronghold(self.secret_manager.clone(), self.timeout).await;
+        self.locked = true;
+    }
+}
+
+impl<S: AsStronghold> Drop for UnlockedStrongholdGuard<S> {
+    fn drop(&mut self) {
+        if !self.locked {
+            // The locks can only be acquired asynchronously, so the Stronghold is locked in a task
+            if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+                runtime.spawn(lock_stronghold(self.secret_manager.clone(), self.timeout));
+            }
+        }
+    }
+}
+
+async fn lock_stronghold<S: AsStronghold>(secret_manager: Arc<RwLock<S>>, timeout: Option<Duration>) {
+    if let Some(stronghold) = secret_manager.write().await.as_stronghold() {
+        stronghold.clear_key().await;
+        stronghold.set_timeout(timeout).await;
+    }
 }
@@ -3,12 +3,17 @@
 
 use std::fmt::Debug;
 
+use primitive_types::U256;
 use serde::{
     ser::{SerializeMap, Serializer},
     Serialize,
 };
 
-use crate::types::block::{address::Bech32Address, payload::transaction::TransactionId};
+use crate::types::block::{
+    address::Bech32Address,
+    output::{AliasId, FoundryId, NftId, OutputId, TokenId},
+    payload::transaction::TransactionId,
+};
 
 /// The wallet error type.
 #[derive(Debug, thiserror::Error)]
@@ -22,9 +27,21 @@ pub enum Error {
     /// Address not found in account
     #[error("address {0} not found in account")]
     AddressNotFoundInAccount(Bech32Address),
+    /// Burning an alias would make the outputs it controls unspendable
+    #[error(
+        "alias {alias_id} still controls foundries {foundries:?} and nfts {nfts:?}, burning it would make them unspendable"
+    )]
+    AliasControlsOutputs {
+        alias_id: AliasId,
+        foundries: Vec<FoundryId>,
+        nfts: Vec<NftId>,
+    },
     /// Errors during backup creation or restoring
     #[error("backup failed {0}")]
     Backup(&'static str),
+    /// The transaction needs blind signing, but the secret manager doesn't support it
+    #[error("the transaction needs blind signing, but it isn't enabled on the Ledger Nano device")]
+    BlindSigningRequired,
     /// Error from block crate.
     #[error("{0}")]
     Block(Box<crate::types::block::Error>),
@@ -46,9 +63,26 @@ pub enum Error {
     /// Failed to get remainder
     #[error("failed to get remainder address")]
     FailedToGetRemainder,
-    /// Insufficient funds to send transaction.
+    /// The foundry output of a native token isn't in the unspent outputs
+    #[error("foundry output of native token {0} not found in unspent outputs")]
+    FoundryNotFound(TokenId),
+    /// The transaction got included, but the node pruned the block that included it
+    #[error("transaction {0} got included, but its block got pruned")]
+    IncludedBlockPruned(TransactionId),
+    /// Insufficient funds to send transaction. Also returned if the storage deposit of the remainder can't be covered,
+    /// `required` includes it then.
     #[error("address owns insufficient funds: {required} base unit required, but {available} base unit available")]
     InsufficientFunds { available: u64, required: u64 },
+    /// Insufficient native tokens to send transaction.
+    #[error("insufficient native tokens {token_id}: {required} required, but {available} available")]
+    InsufficientNativeTokens {
+        /// The token ID.
+        token_id: TokenId,
+        /// The available amount.
+        available: U256,
+        /// The required amount.
+        required: U256,
+    },
     /// Invalid coin type, all accounts need to have the same coin type
     #[error("invalid coin type for new account: {new_coin_type}, existing coin type is: {existing_coin_type}")]
     InvalidCoinType {
@@ -61,6 +95,16 @@ pub enum Error {
     /// Invalid output kind.
     #[error("invalid output kind: {0}")]
     InvalidOutputKind(String),
+    /// Invalid timelock, it needs to be in the future and, if the output also has an expiration, before it.
+    #[error("invalid timelock {timelock}: current time is {local_time}, expiration is {expiration:?}")]
+    InvalidTimelock {
+        /// The requested timelock unix time.
+        timelock: u32,
+        /// The current unix time.
+        local_time: u32,
+        /// The expiration unix time of the output, if any.
+        expiration: Option<u32>,
+    },
     /// IO error. (storage, backup, restore)
     #[error("`{0}`")]
     Io(#[from] std::io::Error),
@@ -79,6 +123,14 @@ pub enum Error {
     /// Nft not found in unspent outputs
     #[error("nft not found in unspent outputs")]
     NftNotFoundInUnspentOutputs,
+    /// No native tokens are available to burn
+    #[error(
+        "no native tokens to burn, skipped native tokens whose foundry is controlled by the account: {skipped_token_ids:?}"
+    )]
+    NoNativeTokensToBurn {
+        /// The native tokens that were skipped because their foundry is controlled by the account.
+        skipped_token_ids: Vec<TokenId>,
+    },
     /// No outputs available for consolidating
     #[error(
         "nothing to consolidate: available outputs: {available_outputs}, consolidation threshold: {consolidation_threshold}"
@@ -89,6 +141,12 @@ pub enum Error {
         /// The consolidation threshold.
         consolidation_threshold: usize,
     },
+    /// No unspent outputs are available to select inputs from.
+    #[error("no spendable inputs available")]
+    NoSpendableInputs,
+    /// Output not found in unspent outputs
+    #[error("output {0} not found in unspent outputs")]
+    OutputNotFoundInUnspentOutputs(OutputId),
     /// Errors not covered by other variants.
     #[error(transparent)]
     Other(#[from] Box<dyn std::error::Error + Send + Sync>),
@@ -97,6 +155,12 @@ pub enum Error {
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
     #[error("participation error {0}")]
     Participation(#[from] crate::types::api::plugins::participation::error::Error),
+    /// Posting a block failed
+    #[error("{0}")]
+    PostBlock(#[from] crate::client::error::PostBlockError),
+    /// Splitting an output failed
+    #[error("splitting output failed: {0}")]
+    SplittingFailed(String),
     /// Storage access error.
     #[error("error accessing storage: {0}")]
     Storage(String),
@@ -109,6 +173,9 @@ pub enum Error {
     /// Transaction not found
     #[error("transaction {0} not found")]
     TransactionNotFound(TransactionId),
+    /// Unknown spent timestamp
+    #[error("spent timestamp of output {0} is unknown")]
+    UnknownSpentTimestamp(OutputId),
     // TODO more precise error
     /// Voting error
     #[cfg(feature = "participation")]
@@ -164,6 +231,16 @@ impl From<crate::client::api::input_selection::Error> for Error {
                     required,
                 }
             }
+            crate::client::api::input_selection::Error::InsufficientNativeTokenAmount {
+                token_id,
+                found,
+                required,
+            } => Self::InsufficientNativeTokens {
+                token_id,
+                available: found,
+                required,
+            },
+            crate::client::api::input_selection::Error::NoAvailableInputsProvided => Self::NoSpendableInputs,
             _ => Self::Client(Box::new(crate::client::Error::InputSelection(error))),
         }
     }
@@ -13,8 +13,16 @@ pub use self::types::{Event, WalletEvent, WalletEventType};
 
 type Handler<T> = Arc<dyn Fn(&T) + Send + Sync + 'static>;
 
+/// The number of events a subscription buffers, further events are dropped for it until it catches up.
+pub(crate) const SUBSCRIPTION_BUFFER_SIZE: usize = 1000;
+
+/// The id of a listener registered with [`EventEmitter::on()`], to remove it with [`EventEmitter::remove()`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ListenerId(usize);
+
 pub struct EventEmitter {
-    handlers: HashMap<WalletEventType, Vec<Handler<Event>>>,
+    handlers: HashMap<WalletEventType, Vec<(ListenerId, Handler<Event>)>>,
+    next_listener_id: usize,
 }
 
 impl EventEmitter {
@@ -22,17 +30,20 @@ impl EventEmitter {
     pub fn new() -> Self {
         Self {
             handlers: HashMap::new(),
+            next_listener_id: 0,
         }
     }
 
     /// Registers function `handler` as a listener for a `WalletEventType`. There may be
-    /// multiple listeners for a single event.
-    pub fn on<F>(&mut self, events: impl IntoIterator<Item = WalletEventType>, handler: F)
+    /// multiple listeners for a single event. Returns the id of the listener.
+    pub fn on<F>(&mut self, events: impl IntoIterator<Item = WalletEventType>, handler: F) -> ListenerId
     where
         F: Fn(&Event) + 'static + Send + Sync,
     {
         let mut events = events.into_iter().peekable();
-        let handler = Arc::new(handler);
+        let handler: Handler<Event> = Arc::new(handler);
+        let listener_id = ListenerId(self.next_listener_id);
+        self.next_listener_id += 1;
         // if no event is provided the handler is registered for all event types
         if events.peek().is_none() {
             // we could use a crate like strum or a macro to iterate over all values, but not sure if it's worth it
@@ -45,12 +56,32 @@ impl EventEmitter {
                 #[cfg(feature = "ledger_nano")]
                 WalletEventType::LedgerAddressGeneration,
             ] {
-                self.handlers.entry(event_type).or_default().push(handler.clone());
+                self.handlers
+                    .entry(event_type)
+                    .or_default()
+                    .push((listener_id, handler.clone()));
             }
         }
         for event in events {
-            self.handlers.entry(event).or_default().push(handler.clone());
+            self.handlers
+                .entry(event)
+                .or_default()
+                .push((listener_id, handler.clone()));
         }
+        listener_id
+    }
+
+    /// Removes the listener with the given id for all event types it was registered for.
+    pub fn remove(&mut self, listener_id: ListenerId) {
+        self.handlers.retain(|_, handlers| {
+            handlers.retain(|(id, _)| *id != listener_id);
+            !handlers.is_empty()
+        });
+    }
+
+    /// Returns whether no listener is registered.
+    pub fn is_empty(&self) -> bool {
+        self.handlers.is_empty()
     }
 
     /// Removes handlers for each given `WalletEventType`.
@@ -80,7 +111,7 @@ impl EventEmitter {
         };
         let event = Event { account_index, event };
         if let Some(handlers) = self.handlers.get(&event_type) {
-            for handler in handlers {
+            for (_, handler) in handlers {
                 handler(&event);
             }
         }
@@ -206,4 +237,31 @@ mod tests {
         }
         assert_eq!(1_000_003, event_counter.load(Ordering::SeqCst));
     }
+
+    #[test]
+    fn remove_listener() {
+        let mut emitter = EventEmitter::new();
+        let event_counter = Arc::new(AtomicUsize::new(0));
+
+        let event_counter_clone = Arc::clone(&event_counter);
+        let removed = emitter.on([], move |_| {
+            event_counter_clone.fetch_add(1, Ordering::SeqCst);
+        });
+        let event_counter_clone = Arc::clone(&event_counter);
+        let kept = emitter.on([WalletEventType::ConsolidationRequired], move |_| {
+            event_counter_clone.fetch_add(10, Ordering::SeqCst);
+        });
+        assert_ne!(removed, kept);
+
+        emitter.remove(removed);
+        emitter.emit(0, WalletEvent::ConsolidationRequired);
+        emitter.emit(
+            0,
+            WalletEvent::TransactionProgress(TransactionProgressEvent::SelectingInputs),
+        );
+        assert_eq!(10, event_counter.load(Ordering::SeqCst));
+
+        emitter.remove(kept);
+        assert!(emitter.is_empty());
+    }
 }
@@ -148,6 +148,14 @@ fn migrations<S: 'static + StorageAdapter>(
                 std::any::type_name::<S>()
             ))
         })?;
+    // A version that isn't known was written by a newer SDK, its data can't be read reliably
+    if let Some(version) = &last_migration {
+        if !migrations.values().any(|migration| migration.version().id == version.id) {
+            return Err(Error::Migration(format!(
+                "unknown migration version {version}, downgrading to an older SDK version is not supported"
+            )));
+        }
+    }
     let mut res = Vec::new();
     while let Some(next) = migrations.get(&last_migration.as_ref().map(|m| m.id)) {
         last_migration = Some(next.version());
@@ -193,3 +201,27 @@ fn rename_keys(json: &mut serde_json::Value) {
         _ => (),
     }
 }
+
+#[cfg(all(test, feature = "storage"))]
+mod tests {
+    use super::*;
+    use crate::wallet::storage::Storage;
+
+    #[test]
+    fn pending_migrations() {
+        assert_eq!(migrations::<Storage>(None).unwrap().len(), 5);
+        assert_eq!(migrations::<Storage>(Some(migrate_2::Migrate::version())).unwrap().len(), 2);
+        assert!(migrations::<Storage>(Some(migrate_4::Migrate::version())).unwrap().is_empty());
+    }
+
+    #[test]
+    fn downgrade_rejected() {
+        let version = MigrationVersion {
+            id: 1000,
+            sdk_version: "1000.0.0".to_string(),
+            date: time::macros::date!(2100 - 01 - 01),
+        };
+
+        assert!(matches!(migrations::<Storage>(Some(version)), Err(Error::Migration(_))));
+    }
+}
@@ -19,6 +19,9 @@ pub(crate) trait DynStorageAdapter: std::fmt::Debug + Send + Sync {
 
     /// Removes a record from the storage.
     async fn dyn_delete(&self, key: &str) -> crate::wallet::Result<()>;
+
+    /// Flushes pending writes to the storage.
+    async fn dyn_flush(&self) -> crate::wallet::Result<()>;
 }
 
 #[async_trait]
@@ -37,6 +40,10 @@ where
     async fn dyn_delete(&self, key: &str) -> crate::wallet::Result<()> {
         Ok(self.delete(key).await?)
     }
+
+    async fn dyn_flush(&self) -> crate::wallet::Result<()> {
+        Ok(self.flush().await?)
+    }
 }
 
 #[async_trait]
@@ -54,4 +61,8 @@ impl StorageAdapter for dyn DynStorageAdapter {
     async fn delete(&self, key: &str) -> Result<(), Self::Error> {
         self.dyn_delete(key).await
     }
+
+    async fn flush(&self) -> Result<(), Self::Error> {
+        self.dyn_flush().await
+    }
 }
@@ -45,4 +45,9 @@ impl StorageAdapter for RocksdbStorageAdapter {
         self.db.lock().await.delete(key)?;
         Ok(())
     }
+
+    async fn flush(&self) -> crate::wallet::Result<()> {
+        self.db.lock().await.flush()?;
+        Ok(())
+    }
 }
@@ -1,7 +1,10 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::BTreeMap;
+
 use futures::{StreamExt, TryStreamExt};
+use instant::Instant;
 use zeroize::Zeroizing;
 
 use crate::{
@@ -10,7 +13,7 @@ use crate::{
     wallet::{
         account::{AccountDetails, AccountDetailsDto, SyncOptions},
         migration::migrate,
-        storage::{constants::*, DynStorageAdapter, Storage},
+        storage::{constants::*, DynStorageAdapter, Storage, StorageWritePolicy},
     },
 };
 
@@ -20,6 +23,11 @@ pub(crate) struct StorageManager {
     pub(crate) storage: Storage,
     // account indexes for accounts in the database
     account_indexes: Vec<u32>,
+    write_policy: StorageWritePolicy,
+    // batched account changes which haven't been written yet, with the time of the oldest one
+    pending_accounts: BTreeMap<u32, AccountDetailsDto>,
+    pending_writes: usize,
+    pending_since: Option<Instant>,
 }
 
 impl StorageManager {
@@ -51,6 +59,10 @@ impl StorageManager {
         let storage_manager = Self {
             storage,
             account_indexes,
+            write_policy: StorageWritePolicy::default(),
+            pending_accounts: BTreeMap::new(),
+            pending_writes: 0,
+            pending_since: None,
         };
 
         Ok(storage_manager)
@@ -76,21 +88,62 @@ impl StorageManager {
             .await
     }
 
+    pub(crate) fn set_write_policy(&mut self, write_policy: StorageWritePolicy) {
+        self.write_policy = write_policy;
+    }
+
+    /// Writes the account immediately, superseding a batched change of it.
     pub(crate) async fn save_account(&mut self, account: &AccountDetails) -> crate::wallet::Result<()> {
+        self.pending_accounts.remove(account.index());
+        self.write_account(*account.index(), &AccountDetailsDto::from(account))
+            .await
+    }
+
+    /// Batches the account change according to the [`StorageWritePolicy`] and writes all batched changes once the
+    /// policy limits are reached.
+    pub(crate) async fn save_account_batched(&mut self, account: &AccountDetails) -> crate::wallet::Result<()> {
+        self.pending_accounts
+            .insert(*account.index(), AccountDetailsDto::from(account));
+        self.pending_writes += 1;
+        let pending_since = *self.pending_since.get_or_insert_with(Instant::now);
+
+        if self.pending_writes >= self.write_policy.max_pending_writes
+            || pending_since.elapsed() >= self.write_policy.max_delay
+        {
+            self.write_pending_accounts().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes all batched changes and flushes the storage.
+    pub(crate) async fn flush_pending(&mut self) -> crate::wallet::Result<()> {
+        self.write_pending_accounts().await?;
+        self.flush().await
+    }
+
+    async fn write_pending_accounts(&mut self) -> crate::wallet::Result<()> {
+        for (index, account) in core::mem::take(&mut self.pending_accounts) {
+            self.write_account(index, &account).await?;
+        }
+        self.pending_writes = 0;
+        self.pending_since = None;
+
+        Ok(())
+    }
+
+    async fn write_account(&mut self, index: u32, account: &AccountDetailsDto) -> crate::wallet::Result<()> {
         // Only add account index if not already present
-        if !self.account_indexes.contains(account.index()) {
-            self.account_indexes.push(*account.index());
+        if !self.account_indexes.contains(&index) {
+            self.account_indexes.push(index);
         }
 
         self.set(ACCOUNTS_INDEXATION_KEY, &self.account_indexes).await?;
-        self.set(
-            &format!("{ACCOUNT_INDEXATION_KEY}{}", account.index()),
-            &AccountDetailsDto::from(account),
-        )
-        .await
+        self.set(&format!("{ACCOUNT_INDEXATION_KEY}{index}"), account).await
     }
 
     pub(crate) async fn remove_account(&mut self, account_index: u32) -> crate::wallet::Result<()> {
+        self.pending_accounts.remove(&account_index);
         self.delete(&format!("{ACCOUNT_INDEXATION_KEY}{account_index}")).await?;
         self.account_indexes.retain(|a| a != &account_index);
         self.set(ACCOUNTS_INDEXATION_KEY, &self.account_indexes).await
@@ -129,6 +182,10 @@ impl StorageAdapter for StorageManager {
     async fn delete(&self, key: &str) -> Result<(), Self::Error> {
         self.storage.delete(key).await
     }
+
+    async fn flush(&self) -> Result<(), Self::Error> {
+        self.storage.flush().await
+    }
 }
 
 #[cfg(test)]
@@ -179,6 +236,27 @@ mod tests {
         assert!(storage_manager.get_accounts().await.unwrap().is_empty());
     }
 
+    #[tokio::test]
+    async fn save_account_batched() {
+        let mut storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
+        storage_manager.set_write_policy(StorageWritePolicy::new(2, std::time::Duration::from_secs(3600)));
+
+        let account_details = AccountDetails::mock();
+
+        storage_manager.save_account_batched(&account_details).await.unwrap();
+        assert!(storage_manager.get_accounts().await.unwrap().is_empty());
+
+        storage_manager.save_account_batched(&account_details).await.unwrap();
+        assert_eq!(storage_manager.get_accounts().await.unwrap().len(), 1);
+
+        storage_manager.remove_account(0).await.unwrap();
+        storage_manager.save_account_batched(&account_details).await.unwrap();
+        assert!(storage_manager.get_accounts().await.unwrap().is_empty());
+
+        storage_manager.flush_pending().await.unwrap();
+        assert_eq!(storage_manager.get_accounts().await.unwrap().len(), 1);
+    }
+
     #[tokio::test]
     async fn save_get_wallet_data() {
         let storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
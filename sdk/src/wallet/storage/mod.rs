@@ -22,7 +22,10 @@ use zeroize::Zeroizing;
 
 use self::adapter::DynStorageAdapter;
 pub(crate) use self::manager::StorageManager;
-pub use self::{kind::StorageKind, options::StorageOptions};
+pub use self::{
+    kind::StorageKind,
+    options::{StorageOptions, StorageWritePolicy},
+};
 use crate::client::storage::StorageAdapter;
 
 #[derive(Debug)]
@@ -61,6 +64,10 @@ impl StorageAdapter for Storage {
     async fn delete(&self, key: &str) -> Result<(), Self::Error> {
         self.inner.as_ref().delete(key).await
     }
+
+    async fn flush(&self) -> Result<(), Self::Error> {
+        self.inner.as_ref().flush().await
+    }
 }
 
 #[cfg(test)]
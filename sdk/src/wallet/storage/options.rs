@@ -1,7 +1,10 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::path::{Path, PathBuf};
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
 use serde::{Deserialize, Serialize};
 use zeroize::Zeroizing;
@@ -17,6 +20,8 @@ pub struct StorageOptions {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub(crate) encryption_key: Option<Zeroizing<[u8; 32]>>,
     pub(crate) kind: StorageKind,
+    #[serde(default)]
+    pub(crate) write_policy: StorageWritePolicy,
 }
 
 #[cfg(feature = "storage")]
@@ -26,6 +31,7 @@ impl Default for StorageOptions {
             path: default_storage_path().into(),
             encryption_key: None,
             kind: StorageKind::default(),
+            write_policy: StorageWritePolicy::default(),
         }
     }
 }
@@ -37,6 +43,7 @@ impl StorageOptions {
             path,
             encryption_key: None,
             kind,
+            write_policy: StorageWritePolicy::default(),
         }
     }
 
@@ -46,6 +53,12 @@ impl StorageOptions {
         self
     }
 
+    /// Sets the [`StorageWritePolicy`] of the [`StorageOptions`].
+    pub fn with_write_policy(mut self, write_policy: StorageWritePolicy) -> Self {
+        self.write_policy = write_policy;
+        self
+    }
+
     /// Returns the path of the [`StorageOptions`];
     pub fn path(&self) -> &Path {
         &self.path
@@ -60,4 +73,45 @@ impl StorageOptions {
     pub fn kind(&self) -> StorageKind {
         self.kind
     }
+
+    /// Returns the write policy of the [`StorageOptions`];
+    pub fn write_policy(&self) -> StorageWritePolicy {
+        self.write_policy
+    }
+}
+
+/// Policy deciding when batched account changes are written to the storage.
+///
+/// Only changes that can be reconstructed by syncing again are batched, like newly synced outputs or addresses.
+/// Changes that matter for double-spend prevention, like sent transactions and their locked inputs, are always written
+/// immediately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageWritePolicy {
+    /// Number of batched changes after which they are written.
+    pub max_pending_writes: usize,
+    /// Maximum time batched changes are kept in memory before being written, checked on every change.
+    pub max_delay: Duration,
+}
+
+impl StorageWritePolicy {
+    /// A policy that writes every change immediately.
+    pub const IMMEDIATE: Self = Self {
+        max_pending_writes: 1,
+        max_delay: Duration::ZERO,
+    };
+
+    /// Creates a new [`StorageWritePolicy`].
+    pub fn new(max_pending_writes: usize, max_delay: Duration) -> Self {
+        Self {
+            max_pending_writes,
+            max_delay,
+        }
+    }
+}
+
+impl Default for StorageWritePolicy {
+    fn default() -> Self {
+        Self::IMMEDIATE
+    }
 }
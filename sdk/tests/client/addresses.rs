@@ -13,7 +13,7 @@ use iota_sdk::{
         secret::{GenerateAddressOptions, SecretManager},
         Client, Result,
     },
-    types::block::address::{Address, Hrp},
+    types::block::address::{Address, Bech32Address, Hrp},
 };
 use pretty_assertions::assert_eq;
 use serde::{Deserialize, Serialize};
@@ -60,6 +60,43 @@ async fn evm_addresses() {
     assert_eq!(internal[0], "0x98d8833ec4b82587d66207eb9c578fd0134c51b6");
 }
 
+#[tokio::test]
+async fn evm_address_bech32_address_conversion() {
+    let secret_manager = crate::client::node_api::setup_secret_manager();
+
+    let public =
+        Bech32Address::try_from_str("atoi1qzt0nhsf38nh6rs4p6zs5knqp6psgha9wsv74uajqgjmwc75ugupx3y7x0r").unwrap();
+    let internal =
+        Bech32Address::try_from_str("atoi1qprxpfvaz2peggq6f8k9cj8zfsxuw69e4nszjyv5kuf8yt70t2847shpjak").unwrap();
+
+    let public_evm = secret_manager
+        .evm_address_from_bech32_address(&public, IOTA_COIN_TYPE, 0, 0..10)
+        .await
+        .unwrap();
+    let internal_evm = secret_manager
+        .evm_address_from_bech32_address(&internal, IOTA_COIN_TYPE, 0, 0..10)
+        .await
+        .unwrap();
+
+    assert_eq!(public_evm, "0xb23e784f0464a30d536c961e414925eab6b3107d");
+    assert_eq!(internal_evm, "0x98d8833ec4b82587d66207eb9c578fd0134c51b6");
+
+    assert_eq!(
+        secret_manager
+            .bech32_address_from_evm_address(&public_evm, IOTA_TESTNET_BECH32_HRP, IOTA_COIN_TYPE, 0, 0..10)
+            .await
+            .unwrap(),
+        public
+    );
+    assert_eq!(
+        secret_manager
+            .bech32_address_from_evm_address(&internal_evm, IOTA_TESTNET_BECH32_HRP, IOTA_COIN_TYPE, 0, 0..10)
+            .await
+            .unwrap(),
+        internal
+    );
+}
+
 #[tokio::test]
 async fn public_key_to_address() {
     let client = Client::builder().finish().await.unwrap();
@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use iota_sdk::{
-    client::{api::input_selection::Error as IsaError, Error},
+    client::{
+        api::input_selection::Error as IsaError, error::PostBlockError, node_api::error::Error as NodeApiError, Error,
+    },
     types::block::Error as BlockError,
 };
 use pretty_assertions::assert_eq;
@@ -24,6 +26,15 @@ fn stringified_error() {
         "{\"type\":\"timeNotSynced\",\"error\":\"local time 0 doesn't match the time of the latest milestone timestamp: 10000\"}"
     );
 
+    let error = Error::InvalidRegularTransactionEssenceLength {
+        length: 40000,
+        max_length: 32000,
+    };
+    assert_eq!(
+        &serde_json::to_string(&error).unwrap(),
+        "{\"type\":\"invalidRegularTransactionEssenceLength\",\"error\":\"the transaction essence is too large. Its length is 40000, max length is 32000; use fewer inputs or outputs, e.g. by splitting it into multiple transactions\"}"
+    );
+
     let error = Error::PlaceholderSecretManager;
     assert_eq!(
         &serde_json::to_string(&error).unwrap(),
@@ -45,3 +56,42 @@ fn stringified_error() {
         "{\"type\":\"inputSelection\",\"error\":\"invalid address provided\"}"
     );
 }
+
+#[test]
+fn post_block_error() {
+    let response_error = |code| {
+        Error::Node(NodeApiError::ResponseError {
+            code,
+            text: String::new(),
+            url: String::new(),
+        })
+    };
+
+    assert!(!PostBlockError::from(response_error(400)).is_retryable());
+    assert!(PostBlockError::from(response_error(408)).is_retryable());
+    assert!(PostBlockError::from(response_error(429)).is_retryable());
+    assert!(PostBlockError::from(response_error(503)).is_retryable());
+    assert!(PostBlockError::from(Error::HealthyNodePoolEmpty).is_retryable());
+    assert!(!PostBlockError::from(Error::Node(NodeApiError::NotFound("block".into()))).is_retryable());
+    assert!(!PostBlockError::from(Error::Node(NodeApiError::UnavailablePow)).is_retryable());
+    assert!(!PostBlockError::from(response_error(300)).is_retryable());
+    assert!(!PostBlockError::from(Error::InvalidAmount("0".into())).is_retryable());
+    assert!(matches!(
+        PostBlockError::from(response_error(400)).into_inner(),
+        Error::Node(NodeApiError::ResponseError { code: 400, .. })
+    ));
+}
+
+#[test]
+fn bech32_hrp_mismatch() {
+    // The check of an address is reported like the check against the connected network
+    let error = Error::from(BlockError::Bech32HrpMismatch {
+        provided: "rms".to_string(),
+        expected: "smr".to_string(),
+    });
+
+    assert!(matches!(
+        error,
+        Error::Bech32HrpMismatch { provided, expected } if provided == "rms" && expected == "smr"
+    ));
+}
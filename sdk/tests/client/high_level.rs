@@ -1,9 +1,15 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::types::block::{
-    address::ToBech32Ext,
-    payload::{transaction::TransactionEssence, Payload},
+use std::{sync::Arc, time::Duration};
+
+use iota_sdk::{
+    client::{clock::MockClock, Client, Error},
+    types::block::{
+        address::ToBech32Ext,
+        output::OutputId,
+        payload::{transaction::TransactionEssence, Payload},
+    },
 };
 use pretty_assertions::assert_eq;
 
@@ -19,6 +25,22 @@ async fn test_find_inputs_from_transaction_id() {
     assert_eq!(inputs.len(), 1);
 }
 
+#[ignore]
+#[tokio::test]
+async fn test_input_signing_data() {
+    let client = setup_client_with_node_health_ignored().await;
+    let (_block_id, transaction_id) = setup_transaction_block(&client).await;
+    let output_id = OutputId::new(transaction_id, 0).unwrap();
+    let input_signing_data = client.input_signing_data(&output_id, None).await.unwrap();
+
+    assert_eq!(input_signing_data.output_id(), &output_id);
+    assert_eq!(
+        &input_signing_data.output,
+        client.get_output(&output_id).await.unwrap().output()
+    );
+    assert!(input_signing_data.chain.is_none());
+}
+
 #[ignore]
 #[tokio::test]
 async fn test_find_blocks() {
@@ -59,3 +81,21 @@ async fn test_find_inputs() {
         unreachable!();
     }
 }
+
+#[tokio::test]
+async fn get_time_checked_with_mock_clock() {
+    let milestone_timestamp = 1_700_000_000;
+    let mut client_builder = Client::builder();
+    client_builder.network_info.latest_milestone_timestamp = Some(milestone_timestamp);
+    let client = client_builder.finish().await.unwrap();
+
+    let clock = Arc::new(MockClock::new(Duration::from_secs(milestone_timestamp as u64)));
+    client.set_clock(clock.clone());
+    assert_eq!(client.get_time_checked().await.unwrap(), milestone_timestamp);
+
+    clock.advance(Duration::from_secs(10 * 60));
+    assert!(matches!(
+        client.get_time_checked().await,
+        Err(Error::TimeNotSynced { current_time, .. }) if current_time == milestone_timestamp + 10 * 60
+    ));
+}
@@ -7,7 +7,10 @@ use iota_sdk::{
     client::api::input_selection::{Error, InputSelection, Requirement},
     types::block::{
         address::{Address, AliasAddress, Bech32Address, NftAddress},
-        output::{AliasId, NftId},
+        output::{
+            feature::{SenderFeature, TagFeature},
+            AliasId, Feature, NftId,
+        },
         protocol::protocol_parameters,
     },
 };
@@ -885,6 +888,93 @@ fn simple_remainder() {
     });
 }
 
+#[test]
+fn remainder_features() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let outputs = build_outputs([Basic(
+        500_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let tag = Feature::from(TagFeature::new(b"change".to_vec()).unwrap());
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        outputs.clone(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .remainder_features([tag.clone()])
+    .select()
+    .unwrap();
+
+    assert!(unsorted_eq(&selected.inputs, &inputs));
+    assert_eq!(selected.outputs.len(), 2);
+    assert!(selected.outputs.contains(&outputs[0]));
+    let remainder = selected.remainder.unwrap().output;
+    assert_eq!(remainder.amount(), 500_000);
+    assert_eq!(remainder.features().unwrap().tag(), Some(tag.as_tag()));
+}
+
+#[test]
+fn unsupported_remainder_feature() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let outputs = build_outputs([Basic(
+        500_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let sender = Feature::from(SenderFeature::new(
+        Address::try_from_bech32(BECH32_ADDRESS_ED25519_0).unwrap(),
+    ));
+
+    let selected = InputSelection::new(
+        inputs,
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .remainder_features([sender])
+    .select();
+
+    assert_eq!(
+        selected.unwrap_err(),
+        Error::UnsupportedRemainderFeature(SenderFeature::KIND)
+    );
+}
+
 #[test]
 fn remainder_lower_than_rent() {
     let protocol_parameters = protocol_parameters();
@@ -1357,6 +1447,41 @@ fn too_many_outputs() {
     )
 }
 
+#[test]
+fn too_many_outputs_beyond_u16() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([Basic(
+        2_000_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    // 65_537 doesn't fit in a u16 and must not wrap around to a valid count of 1
+    let outputs = build_outputs(
+        std::iter::repeat_with(|| Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None))
+            .take(u16::MAX as usize + 2),
+    );
+
+    let selected = InputSelection::new(
+        inputs,
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .select();
+
+    assert_eq!(
+        selected.unwrap_err(),
+        iota_sdk::client::api::input_selection::Error::InvalidOutputCount(u16::MAX as usize + 2)
+    )
+}
+
 #[test]
 fn too_many_outputs_with_remainder() {
     let protocol_parameters = protocol_parameters();
@@ -10,11 +10,12 @@ use iota_sdk::{
     client::api::input_selection::{Burn, Error, InputSelection, Requirement},
     types::block::{
         address::Address,
-        output::{AliasId, AliasTransition, ChainId, NftId, SimpleTokenScheme, TokenId},
+        output::{AliasId, AliasTransition, ChainId, FoundryId, NativeToken, NftId, SimpleTokenScheme, TokenId},
         protocol::protocol_parameters,
     },
 };
 use pretty_assertions::assert_eq;
+use primitive_types::U256;
 
 use crate::client::{
     addresses, build_inputs, build_outputs, is_remainder_or_return, unsorted_eq,
@@ -886,6 +887,49 @@ fn burn_native_tokens() {
     ));
 }
 
+#[test]
+fn burn_half_of_native_tokens() {
+    let protocol_parameters = protocol_parameters();
+    let token_id_1 = TokenId::from_str(TOKEN_ID_1).unwrap();
+
+    let inputs = build_inputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        Some(vec![(TOKEN_ID_1, 100)]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        Vec::new(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .burn(Burn::new().add_native_token(token_id_1, 50))
+    .select()
+    .unwrap();
+
+    assert!(unsorted_eq(&selected.inputs, &inputs));
+    assert_eq!(selected.outputs.len(), 1);
+    assert!(is_remainder_or_return(
+        &selected.outputs[0],
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        Some(vec![(TOKEN_ID_1, 50)])
+    ));
+    // The un-burned tokens are returned in the remainder
+    let remainder = selected.remainder.unwrap();
+    assert_eq!(remainder.output, selected.outputs[0]);
+    assert_eq!(
+        remainder.address,
+        Address::try_from_bech32(BECH32_ADDRESS_ED25519_0).unwrap()
+    );
+}
+
 #[test]
 fn burn_foundry_and_its_alias() {
     let protocol_parameters = protocol_parameters();
@@ -941,3 +985,125 @@ fn burn_foundry_and_its_alias() {
         Err(Error::UnfulfillableRequirement(Requirement::Alias(alias_id, AliasTransition::State))) if alias_id == alias_id_1
     ));
 }
+
+#[test]
+fn burn_builder() {
+    let alias_id_1 = AliasId::from_str(ALIAS_ID_1).unwrap();
+    let nft_id_1 = NftId::from_str(NFT_ID_1).unwrap();
+    let token_id_1 = TokenId::from_str(TOKEN_ID_1).unwrap();
+
+    let burn = Burn::builder()
+        .add_alias(alias_id_1)
+        .add_nft(nft_id_1)
+        .add_native_token(token_id_1, 50)
+        .finish()
+        .unwrap();
+
+    assert_eq!(
+        burn,
+        Burn::new()
+            .add_alias(alias_id_1)
+            .add_nft(nft_id_1)
+            .add_native_token(token_id_1, 50)
+    );
+}
+
+#[test]
+fn burn_builder_duplicate_ids() {
+    let nft_id_1 = NftId::from_str(NFT_ID_1).unwrap();
+    let token_id_1 = TokenId::from_str(TOKEN_ID_1).unwrap();
+
+    assert_eq!(
+        Burn::builder().add_nft(nft_id_1).add_nft(nft_id_1).finish(),
+        Err(Error::DuplicateBurnChainId(ChainId::from(nft_id_1)))
+    );
+    assert_eq!(
+        Burn::builder()
+            .add_native_token(token_id_1, 50)
+            .add_native_token(token_id_1, 20)
+            .finish(),
+        Err(Error::DuplicateBurnNativeToken(token_id_1))
+    );
+}
+
+#[test]
+fn burn_builder_zero_native_token_amount() {
+    let token_id_1 = TokenId::from_str(TOKEN_ID_1).unwrap();
+
+    assert_eq!(
+        Burn::builder().add_native_token(token_id_1, 0).finish(),
+        Err(Error::ZeroNativeTokenBurnAmount(token_id_1))
+    );
+}
+
+#[test]
+fn burn_from_single_items() {
+    let alias_id_1 = AliasId::from_str(ALIAS_ID_1).unwrap();
+    let nft_id_1 = NftId::from_str(NFT_ID_1).unwrap();
+    let token_id_1 = TokenId::from_str(TOKEN_ID_1).unwrap();
+    let foundry_id = FoundryId::from(token_id_1);
+
+    assert_eq!(Burn::from(alias_id_1), Burn::new().add_alias(alias_id_1));
+    assert_eq!(Burn::from(nft_id_1), Burn::new().add_nft(nft_id_1));
+    assert_eq!(Burn::from(foundry_id), Burn::new().add_foundry(foundry_id));
+    assert_eq!(
+        Burn::from((token_id_1, U256::from(50))),
+        Burn::new().add_native_token(token_id_1, 50)
+    );
+    assert_eq!(
+        Burn::from(NativeToken::new(token_id_1, 50).unwrap()),
+        Burn::from((token_id_1, U256::from(50)))
+    );
+}
+
+#[test]
+fn burn_len_and_chain_ids() {
+    let alias_id_1 = AliasId::from_str(ALIAS_ID_1).unwrap();
+    let nft_id_1 = NftId::from_str(NFT_ID_1).unwrap();
+    let token_id_1 = TokenId::from_str(TOKEN_ID_1).unwrap();
+    let foundry_id = FoundryId::from(token_id_1);
+
+    assert!(Burn::new().is_empty());
+
+    let burn = Burn::new()
+        .add_alias(alias_id_1)
+        .add_nft(nft_id_1)
+        .add_foundry(foundry_id)
+        .add_native_token(token_id_1, 50);
+
+    assert_eq!(burn.len(), 4);
+    assert!(!burn.is_empty());
+    assert_eq!(
+        burn.chain_ids().collect::<HashSet<_>>(),
+        HashSet::from([
+            ChainId::from(alias_id_1),
+            ChainId::from(nft_id_1),
+            ChainId::from(foundry_id)
+        ])
+    );
+}
+
+#[test]
+fn burn_merge() {
+    let alias_id_1 = AliasId::from_str(ALIAS_ID_1).unwrap();
+    let nft_id_1 = NftId::from_str(NFT_ID_1).unwrap();
+    let token_id_1 = TokenId::from_str(TOKEN_ID_1).unwrap();
+
+    assert_eq!(
+        Burn::from(alias_id_1)
+            .merge(Burn::new().add_nft(nft_id_1).add_native_token(token_id_1, 50))
+            .unwrap(),
+        Burn::new()
+            .add_alias(alias_id_1)
+            .add_nft(nft_id_1)
+            .add_native_token(token_id_1, 50)
+    );
+    assert_eq!(
+        Burn::from(nft_id_1).merge(Burn::from(nft_id_1)),
+        Err(Error::DuplicateBurnChainId(ChainId::from(nft_id_1)))
+    );
+    assert_eq!(
+        Burn::from((token_id_1, U256::from(50))).merge(Burn::from((token_id_1, U256::from(20)))),
+        Err(Error::DuplicateBurnNativeToken(token_id_1))
+    );
+}
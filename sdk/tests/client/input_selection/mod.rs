@@ -9,5 +9,6 @@ mod foundry_outputs;
 mod native_tokens;
 mod nft_outputs;
 mod outputs;
+mod properties;
 mod storage_deposit_return;
 mod timelock;
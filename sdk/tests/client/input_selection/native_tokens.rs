@@ -310,6 +310,50 @@ fn insufficient_native_tokens_one_input() {
         }) if token_id == TokenId::from_str(TOKEN_ID_1).unwrap() && found == U256::from(100) && required == U256::from(150)));
 }
 
+#[cfg(feature = "wallet")]
+#[test]
+fn insufficient_native_tokens_wallet_error() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        Some(vec![(TOKEN_ID_1, 100)]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let outputs = build_outputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        Some(vec![(TOKEN_ID_1, 150)]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let error = InputSelection::new(
+        inputs,
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .select()
+    .unwrap_err();
+
+    assert!(matches!(
+        iota_sdk::wallet::Error::from(error),
+        iota_sdk::wallet::Error::InsufficientNativeTokens {
+            token_id,
+            available,
+            required,
+        } if token_id == TokenId::from_str(TOKEN_ID_1).unwrap() && available == U256::from(100) && required == U256::from(150)));
+}
+
 #[test]
 fn insufficient_native_tokens_three_inputs() {
     let protocol_parameters = protocol_parameters();
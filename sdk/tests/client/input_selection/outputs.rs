@@ -42,6 +42,37 @@ fn no_inputs() {
     assert!(matches!(selected, Err(Error::NoAvailableInputsProvided)));
 }
 
+#[cfg(feature = "wallet")]
+#[test]
+fn no_inputs_wallet_error() {
+    let protocol_parameters = protocol_parameters();
+
+    let outputs = build_outputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let error = InputSelection::new(
+        Vec::new(),
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .select()
+    .unwrap_err();
+
+    assert!(matches!(
+        iota_sdk::wallet::Error::from(error),
+        iota_sdk::wallet::Error::NoSpendableInputs
+    ));
+}
+
 #[test]
 fn no_outputs() {
     let protocol_parameters = protocol_parameters();
@@ -0,0 +1,156 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use iota_sdk::{
+    client::api::input_selection::{Error, InputSelection},
+    types::block::{
+        output::{NativeTokensBuilder, Output},
+        protocol::protocol_parameters,
+        rand::{bool::rand_bool, number::rand_number_range},
+    },
+};
+
+use crate::client::{
+    addresses, build_inputs, build_outputs, Build::Basic, BECH32_ADDRESS_ED25519_0, BECH32_ADDRESS_ED25519_1,
+    TOKEN_ID_1, TOKEN_ID_2,
+};
+
+const ITERATIONS: usize = 200;
+
+fn rand_native_tokens(max_amount: u64) -> Option<Vec<(&'static str, u64)>> {
+    let native_tokens = [TOKEN_ID_1, TOKEN_ID_2]
+        .into_iter()
+        .filter(|_| rand_bool())
+        .map(|token_id| (token_id, rand_number_range(1..=max_amount)))
+        .collect::<Vec<_>>();
+
+    (!native_tokens.is_empty()).then_some(native_tokens)
+}
+
+fn native_tokens_sum<'a>(outputs: impl IntoIterator<Item = &'a Output>) -> NativeTokensBuilder {
+    let mut native_tokens = NativeTokensBuilder::new();
+    for output in outputs {
+        if let Some(output_native_tokens) = output.native_tokens() {
+            native_tokens.add_native_tokens(output_native_tokens.clone()).unwrap();
+        }
+    }
+    native_tokens
+}
+
+// Runs the input selection on random pools of basic outputs and random outputs, then checks that the selection
+// is balanced and valid, or that it failed for a legitimate reason.
+#[test]
+fn random_basic_outputs() {
+    let protocol_parameters = protocol_parameters();
+
+    for _ in 0..ITERATIONS {
+        let inputs = build_inputs((0..rand_number_range(1..=10)).map(|_| {
+            Basic(
+                rand_number_range(1_000_000..=10_000_000),
+                BECH32_ADDRESS_ED25519_0,
+                rand_native_tokens(100),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+        }));
+        let outputs = build_outputs((0..rand_number_range(1..=3)).map(|_| {
+            Basic(
+                rand_number_range(1_000_000..=10_000_000),
+                BECH32_ADDRESS_ED25519_1,
+                rand_native_tokens(50),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+        }));
+        let forbidden_inputs = inputs
+            .iter()
+            .filter(|_| rand_number_range(0..4) == 0)
+            .map(|input| *input.output_id())
+            .collect::<HashSet<_>>();
+
+        let result = InputSelection::new(
+            inputs.clone(),
+            outputs.clone(),
+            addresses([BECH32_ADDRESS_ED25519_0]),
+            protocol_parameters.clone(),
+        )
+        .forbidden_inputs(forbidden_inputs.clone())
+        .select();
+
+        let context = format!("inputs: {inputs:?}\noutputs: {outputs:?}\nforbidden inputs: {forbidden_inputs:?}");
+
+        match result {
+            Ok(selected) => {
+                // No forbidden input is used.
+                assert!(
+                    selected
+                        .inputs
+                        .iter()
+                        .all(|input| !forbidden_inputs.contains(input.output_id())),
+                    "{context}"
+                );
+                // Every requested output is created.
+                assert!(
+                    outputs.iter().all(|output| selected.outputs.contains(output)),
+                    "{context}"
+                );
+                // Amounts balance.
+                assert_eq!(
+                    selected.inputs.iter().map(|input| input.output.amount()).sum::<u64>(),
+                    selected.outputs.iter().map(Output::amount).sum::<u64>(),
+                    "{context}"
+                );
+                // Native tokens balance.
+                assert_eq!(
+                    *native_tokens_sum(selected.inputs.iter().map(|input| &input.output)),
+                    *native_tokens_sum(&selected.outputs),
+                    "{context}"
+                );
+                // Every output, including the remainder, covers its storage deposit.
+                for output in &selected.outputs {
+                    output
+                        .verify_storage_deposit(
+                            *protocol_parameters.rent_structure(),
+                            protocol_parameters.token_supply(),
+                        )
+                        .unwrap_or_else(|error| panic!("{error}\n{context}"));
+                }
+                if let Some(remainder) = &selected.remainder {
+                    assert!(selected.outputs.contains(&remainder.output), "{context}");
+                }
+            }
+            Err(Error::InsufficientAmount { found, required }) => {
+                assert!(found < required, "{context}");
+            }
+            Err(Error::InsufficientNativeTokenAmount {
+                token_id,
+                found,
+                required,
+            }) => {
+                assert!(found < required, "{context}");
+                let available = native_tokens_sum(
+                    inputs
+                        .iter()
+                        .filter(|input| !forbidden_inputs.contains(input.output_id()))
+                        .map(|input| &input.output),
+                );
+                assert!(
+                    available.get(&token_id).map_or(true, |available| *available < required),
+                    "{context}"
+                );
+            }
+            Err(Error::NoAvailableInputsProvided) => {
+                assert_eq!(forbidden_inputs.len(), inputs.len(), "{context}");
+            }
+            Err(error) => panic!("unexpected error: {error}\n{context}"),
+        }
+    }
+}
@@ -7,7 +7,7 @@ use iota_sdk::{
     client::{api::GetAddressesOptions, node_api::indexer::query_parameters::QueryParameter, Client, NodeInfoWrapper},
     types::block::{
         output::{Output, OutputId},
-        payload::Payload,
+        payload::{transaction::TransactionId, Payload},
         Block,
     },
 };
@@ -379,3 +379,39 @@ async fn test_get_included_block_metadata() {
 
     println!("{metadata_response:#?}");
 }
+
+#[ignore]
+#[tokio::test]
+async fn test_get_included_blocks_metadata() {
+    let client = setup_client_with_node_health_ignored().await;
+    let (block_id, transaction_id) = setup_transaction_block(&client).await;
+    let unknown_transaction_id = TransactionId::new([1; 32]);
+
+    let metadata = client
+        .get_included_blocks_metadata(&[transaction_id, unknown_transaction_id])
+        .await
+        .unwrap();
+
+    // Transactions that aren't included are missing from the map
+    assert_eq!(metadata.len(), 1);
+    assert_eq!(metadata[&transaction_id].block_id, block_id);
+}
+
+#[ignore]
+#[tokio::test]
+async fn test_get_and_post_json() {
+    let client = setup_client_with_node_health_ignored().await;
+
+    let info = client.get_json("api/core/v2/info", None).await.unwrap();
+    // Just check name as info can change between 2 calls
+    assert_eq!(info["name"], client.get_info().await.unwrap().node_info.name);
+
+    // Posting a block the node already knows returns its id
+    let block_id = setup_tagged_data_block().await;
+    let block = client
+        .get_json(&format!("api/core/v2/blocks/{block_id}"), None)
+        .await
+        .unwrap();
+    let response = client.post_json("api/core/v2/blocks", block).await.unwrap();
+    assert_eq!(response["blockId"], block_id.to_string());
+}
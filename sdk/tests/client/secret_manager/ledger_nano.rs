@@ -0,0 +1,93 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::bip44::Bip44;
+use iota_sdk::{
+    client::{
+        api::{PreparedTransactionData, RemainderData},
+        constants::SHIMMER_COIN_TYPE,
+        secret::ledger_nano::{LedgerDisplay, LedgerDisplayOutput},
+    },
+    types::block::{
+        address::Bech32Address,
+        input::{Input, UtxoInput},
+        output::InputsCommitment,
+        payload::transaction::{RegularTransactionEssence, TransactionEssence},
+        protocol::protocol_parameters,
+    },
+};
+use pretty_assertions::assert_eq;
+
+use crate::client::{build_inputs, build_outputs, Build::Basic, BECH32_ADDRESS_ED25519_0, BECH32_ADDRESS_ED25519_1};
+
+#[test]
+fn ledger_display_summary() {
+    let protocol_parameters = protocol_parameters();
+    let address_0 = Bech32Address::try_from_str(BECH32_ADDRESS_ED25519_0).unwrap();
+    let address_1 = Bech32Address::try_from_str(BECH32_ADDRESS_ED25519_1).unwrap();
+
+    let inputs = build_inputs([Basic(
+        3_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(Bip44::new(SHIMMER_COIN_TYPE)),
+    )]);
+    let outputs = build_outputs([
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_1, None, None, None, None, None, None),
+        Basic(2_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect::<Vec<_>>(),
+        )
+        .with_outputs(outputs.clone())
+        .finish_with_params(&protocol_parameters)
+        .unwrap(),
+    );
+
+    let prepared_transaction_data = PreparedTransactionData {
+        essence,
+        inputs_data: inputs,
+        remainder: Some(RemainderData {
+            output: outputs[1].clone(),
+            chain: Some(Bip44::new(SHIMMER_COIN_TYPE)),
+            address: *address_0.inner(),
+        }),
+    };
+
+    assert_eq!(
+        prepared_transaction_data.ledger_display_summary(*address_0.hrp(), usize::MAX),
+        LedgerDisplay::Outputs(vec![
+            LedgerDisplayOutput {
+                address: address_1,
+                amount: 1_000_000,
+                remainder: false,
+            },
+            LedgerDisplayOutput {
+                address: address_0.clone(),
+                amount: 2_000_000,
+                remainder: true,
+            },
+        ])
+    );
+
+    // The essence doesn't fit into the buffer of the device
+    assert_eq!(
+        prepared_transaction_data.ledger_display_summary(*address_0.hrp(), 0),
+        LedgerDisplay::BlindSigning {
+            essence_hash: prepared_transaction_data.essence.hash(),
+        }
+    );
+}
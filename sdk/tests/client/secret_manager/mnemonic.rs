@@ -1,8 +1,14 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::client::{
-    api::GetAddressesOptions, constants::SHIMMER_TESTNET_BECH32_HRP, secret::SecretManager, Result,
+use iota_sdk::{
+    client::{
+        api::GetAddressesOptions,
+        constants::{SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
+        secret::{SecretManage, SecretManager, SignerCapabilities},
+        Client, Result,
+    },
+    types::block::address::ToBech32Ext,
 };
 use pretty_assertions::assert_eq;
 
@@ -28,3 +34,41 @@ async fn mnemonic_secret_manager() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn mnemonic_secret_manager_public_descriptor() -> Result<()> {
+    let dto = r#"{"mnemonic": "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast"}"#;
+    let secret_manager: SecretManager = dto.parse()?;
+
+    let descriptor = secret_manager
+        .export_public_descriptor(SHIMMER_COIN_TYPE, 0, 0..2)
+        .await?;
+
+    assert_eq!(descriptor.address_index_start, 0);
+    assert_eq!(descriptor.public_addresses.len(), 2);
+    assert_eq!(descriptor.internal_addresses.len(), 2);
+    assert_eq!(
+        descriptor.public_addresses[0].to_bech32(SHIMMER_TESTNET_BECH32_HRP),
+        "rms1qzev36lk0gzld0k28fd2fauz26qqzh4hd4cwymlqlv96x7phjxcw6v3ea5a"
+    );
+    assert_ne!(descriptor.public_addresses[0], descriptor.internal_addresses[0]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mnemonic_secret_manager_capabilities() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+
+    assert_eq!(secret_manager.capabilities().await?, SignerCapabilities::default());
+    assert_eq!(
+        SecretManager::Placeholder.capabilities().await?,
+        SignerCapabilities {
+            secp256k1_ecdsa: false,
+            blind_signing: false,
+            buffer_size: None,
+        }
+    );
+
+    Ok(())
+}
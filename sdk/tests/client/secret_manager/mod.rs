@@ -1,6 +1,8 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "ledger_nano")]
+mod ledger_nano;
 mod mnemonic;
 #[cfg(feature = "private_key_secret_manager")]
 mod private_key;
@@ -1,33 +1,38 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::str::FromStr;
+
 use crypto::keys::bip44::Bip44;
 use iota_sdk::{
     client::{
         api::{
             transaction::validate_transaction_payload_length, verify_semantic, GetAddressesOptions,
-            PreparedTransactionData,
+            PreparedTransactionData, PreparedTransactionError, RemainderData, SignedTransactionData,
         },
         constants::{SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
-        secret::{SecretManage, SecretManager},
-        Client, Result,
+        secret::{types::InputSigningData, SecretManage, SecretManager},
+        Client, Error, Result,
     },
     types::block::{
-        address::ToBech32Ext,
+        address::{Bech32Address, ToBech32Ext},
         input::{Input, UtxoInput},
-        output::InputsCommitment,
+        output::{InputsCommitment, Output, TokenId},
         payload::{
             transaction::{RegularTransactionEssence, TransactionEssence},
             TransactionPayload,
         },
         protocol::protocol_parameters,
-        semantic::ConflictReason,
+        semantic::{BalanceError, ConflictReason},
         unlock::{SignatureUnlock, Unlock},
     },
 };
 use pretty_assertions::assert_eq;
 
-use crate::client::{build_inputs, build_outputs, Build::Basic};
+use crate::client::{
+    build_inputs, build_outputs, Build::Basic, BECH32_ADDRESS_ALIAS_1, BECH32_ADDRESS_ED25519_0,
+    BECH32_ADDRESS_ED25519_1, BECH32_ADDRESS_ED25519_2, TOKEN_ID_1,
+};
 
 #[tokio::test]
 async fn single_ed25519_unlock() -> Result<()> {
@@ -323,3 +328,341 @@ async fn two_signature_unlocks() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn prepared_transaction_matches_signed() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+
+    let bech32_address_0 = &secret_manager
+        .generate_ed25519_addresses(
+            GetAddressesOptions::default()
+                .with_coin_type(SHIMMER_COIN_TYPE)
+                .with_range(0..1),
+        )
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([Basic(
+        2_000_000,
+        &bech32_address_0.to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(Bip44::new(SHIMMER_COIN_TYPE)),
+    )]);
+
+    let build_essence = |amount| {
+        TransactionEssence::Regular(
+            RegularTransactionEssence::builder(
+                protocol_parameters.network_id(),
+                InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+            )
+            .with_inputs(
+                inputs
+                    .iter()
+                    .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                    .collect::<Vec<_>>(),
+            )
+            .with_outputs(build_outputs([Basic(
+                amount,
+                &bech32_address_0.to_string(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )]))
+            .finish_with_params(&protocol_parameters)
+            .unwrap(),
+        )
+    };
+
+    let prepared_transaction_data = PreparedTransactionData {
+        essence: build_essence(2_000_000),
+        inputs_data: inputs.clone(),
+        remainder: None,
+    };
+    let other_prepared_transaction_data = PreparedTransactionData {
+        essence: build_essence(1_000_000),
+        inputs_data: inputs.clone(),
+        remainder: None,
+    };
+
+    let unlocks = secret_manager
+        .sign_transaction_essence(&other_prepared_transaction_data, Some(0))
+        .await?;
+    let signed_transaction_data = SignedTransactionData {
+        transaction_payload: TransactionPayload::new(other_prepared_transaction_data.essence.clone(), unlocks)?,
+        inputs_data: inputs,
+    };
+
+    assert!(other_prepared_transaction_data.matches_signed(&signed_transaction_data));
+    assert!(!prepared_transaction_data.matches_signed(&signed_transaction_data));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn prepared_transaction_chunks() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+
+    let bech32_address_0 = &secret_manager
+        .generate_ed25519_addresses(
+            GetAddressesOptions::default()
+                .with_coin_type(SHIMMER_COIN_TYPE)
+                .with_range(0..1),
+        )
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([Basic(
+        1_000_000,
+        &bech32_address_0.to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(Bip44::new(SHIMMER_COIN_TYPE)),
+    )]);
+
+    let outputs = build_outputs([Basic(
+        1_000_000,
+        &bech32_address_0.to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect::<Vec<_>>(),
+        )
+        .with_outputs(outputs)
+        .finish_with_params(protocol_parameters)?,
+    );
+
+    let prepared_transaction_data = PreparedTransactionData {
+        essence,
+        inputs_data: inputs,
+        remainder: None,
+    };
+
+    let mut chunks = prepared_transaction_data.to_chunks(100)?;
+    assert!(chunks.len() > 1);
+    assert!(chunks.iter().all(|chunk| chunk.len() <= 100 * 2 + 12));
+
+    // The order doesn't matter
+    chunks.reverse();
+    assert_eq!(
+        PreparedTransactionData::from_chunks(&chunks)?,
+        prepared_transaction_data
+    );
+
+    chunks.pop();
+    assert!(matches!(
+        PreparedTransactionData::from_chunks(&chunks),
+        Err(Error::InvalidChunk(_))
+    ));
+    assert!(matches!(
+        PreparedTransactionData::from_chunks(["1/0x00"]),
+        Err(Error::InvalidChunk(_))
+    ));
+    // A huge count is rejected without allocating for it
+    assert!(matches!(
+        PreparedTransactionData::from_chunks([format!("1/{}/0x00", usize::MAX)]),
+        Err(Error::InvalidChunk(_))
+    ));
+    assert!(matches!(
+        prepared_transaction_data.to_chunks(0),
+        Err(Error::InvalidChunk(_))
+    ));
+
+    Ok(())
+}
+
+fn prepared_transaction_data(
+    inputs: Vec<InputSigningData>,
+    outputs: Vec<Output>,
+    remainder: Option<RemainderData>,
+) -> Result<PreparedTransactionData> {
+    let protocol_parameters = protocol_parameters();
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect::<Vec<_>>(),
+        )
+        .with_outputs(outputs)
+        .finish_with_params(&protocol_parameters)?,
+    );
+
+    Ok(PreparedTransactionData {
+        essence,
+        inputs_data: inputs,
+        remainder,
+    })
+}
+
+#[test]
+fn prepared_transaction_verify_semantic() -> Result<()> {
+    let protocol_parameters = protocol_parameters();
+    let current_time = 100;
+    let basic = |amount, address, native_tokens, sdruc, timelock| {
+        Basic(amount, address, native_tokens, None, sdruc, timelock, None, None)
+    };
+    let address = |bech32_address| Bech32Address::try_from_str(bech32_address).unwrap().into_inner();
+
+    let inputs = build_inputs([basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None)]);
+    let outputs = build_outputs([basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None)]);
+    prepared_transaction_data(inputs.clone(), outputs.clone(), None)?
+        .verify_semantic(&protocol_parameters, current_time)
+        .unwrap();
+
+    let mut prepared = prepared_transaction_data(inputs.clone(), outputs, None)?;
+    prepared.inputs_data.clear();
+    assert_eq!(
+        prepared.verify_semantic(&protocol_parameters, current_time),
+        Err(PreparedTransactionError::InputsCountMismatch {
+            essence: 1,
+            inputs_data: 0
+        })
+    );
+
+    let outputs = build_outputs([basic(900_000, BECH32_ADDRESS_ED25519_0, None, None, None)]);
+    assert_eq!(
+        prepared_transaction_data(inputs, outputs, None)?.verify_semantic(&protocol_parameters, current_time),
+        Err(PreparedTransactionError::Balance(BalanceError::AmountMismatch {
+            input: 1_000_000,
+            output: 900_000
+        }))
+    );
+
+    // The alias owning the input isn't unlocked by another input
+    let inputs = build_inputs([basic(1_000_000, BECH32_ADDRESS_ALIAS_1, None, None, None)]);
+    let outputs = build_outputs([basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None)]);
+    assert_eq!(
+        prepared_transaction_data(inputs.clone(), outputs, None)?.verify_semantic(&protocol_parameters, current_time),
+        Err(PreparedTransactionError::MissingUnlock {
+            output_id: *inputs[0].output_id(),
+            address: address(BECH32_ADDRESS_ALIAS_1),
+        })
+    );
+
+    let inputs = build_inputs([basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, Some(200))]);
+    let outputs = build_outputs([basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None)]);
+    assert_eq!(
+        prepared_transaction_data(inputs.clone(), outputs, None)?.verify_semantic(&protocol_parameters, current_time),
+        Err(PreparedTransactionError::Balance(BalanceError::TimelockNotExpired(
+            *inputs[0].output_id()
+        )))
+    );
+
+    let inputs = build_inputs([basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        Some((BECH32_ADDRESS_ED25519_1, 100_000)),
+        None,
+    )]);
+    let outputs = build_outputs([basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None)]);
+    assert_eq!(
+        prepared_transaction_data(inputs, outputs, None)?.verify_semantic(&protocol_parameters, current_time),
+        Err(PreparedTransactionError::Balance(
+            BalanceError::StorageDepositReturnUnfulfilled(address(BECH32_ADDRESS_ED25519_1))
+        ))
+    );
+
+    // Native tokens can't be minted without their foundry
+    let inputs = build_inputs([basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        Some(vec![(TOKEN_ID_1, 100)]),
+        None,
+        None,
+    )]);
+    let outputs = build_outputs([basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        Some(vec![(TOKEN_ID_1, 150)]),
+        None,
+        None,
+    )]);
+    assert_eq!(
+        prepared_transaction_data(inputs, outputs, None)?.verify_semantic(&protocol_parameters, current_time),
+        Err(PreparedTransactionError::Balance(
+            BalanceError::NativeTokenAmountMismatch {
+                token_id: TokenId::from_str(TOKEN_ID_1)?,
+                input: 100u64.into(),
+                output: 150u64.into(),
+            }
+        ))
+    );
+
+    let inputs = build_inputs([basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None)]);
+    let outputs = build_outputs([
+        basic(999_999, BECH32_ADDRESS_ED25519_0, None, None, None),
+        basic(1, BECH32_ADDRESS_ED25519_1, None, None, None),
+    ]);
+    let remainder = |output: &Output, bech32_address| {
+        Some(RemainderData {
+            output: output.clone(),
+            chain: None,
+            address: address(bech32_address),
+        })
+    };
+    let other_output = build_outputs([basic(1, BECH32_ADDRESS_ED25519_2, None, None, None)]).remove(0);
+
+    assert_eq!(
+        prepared_transaction_data(
+            inputs.clone(),
+            outputs.clone(),
+            remainder(&other_output, BECH32_ADDRESS_ED25519_2)
+        )?
+        .verify_semantic(&protocol_parameters, current_time),
+        Err(PreparedTransactionError::RemainderNotFound)
+    );
+    assert_eq!(
+        prepared_transaction_data(
+            inputs.clone(),
+            outputs.clone(),
+            remainder(&outputs[1], BECH32_ADDRESS_ED25519_0)
+        )?
+        .verify_semantic(&protocol_parameters, current_time),
+        Err(PreparedTransactionError::RemainderAddressMismatch)
+    );
+    // The remainder doesn't cover its storage deposit
+    assert!(matches!(
+        prepared_transaction_data(
+            inputs,
+            outputs.clone(),
+            remainder(&outputs[1], BECH32_ADDRESS_ED25519_1)
+        )?
+        .verify_semantic(&protocol_parameters, current_time),
+        Err(PreparedTransactionError::RemainderStorageDeposit(_))
+    ));
+
+    Ok(())
+}
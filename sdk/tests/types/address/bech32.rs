@@ -4,7 +4,7 @@
 use core::str::FromStr;
 
 use iota_sdk::types::block::{
-    address::{Address, Bech32Address, Ed25519Address, Hrp},
+    address::{Address, Bech32Address, Ed25519Address, Hrp, ToBech32Ext},
     Error,
 };
 use packable::PackableExt;
@@ -12,6 +12,8 @@ use pretty_assertions::assert_eq;
 
 const ED25519_ADDRESS: &str = "0xebe40a263480190dcd7939447ee01aefa73d6f3cc33c90ef7bf905abf8728655";
 const ED25519_BECH32: &str = "rms1qr47gz3xxjqpjrwd0yu5glhqrth6w0t08npney8000ust2lcw2r92j5a8rt";
+const ALIAS_BECH32: &str = "rms1pr5m4q9dz4s7gdakvwslrmal4025fvxhmfamx0s2vt5ekg8wg597um6lcnn";
+const NFT_BECH32: &str = "rms1zz57m6v20upz87n6f8autphh4z9mfux32teg9vvme67stj0g5q3hqd6l53z";
 
 #[test]
 fn debug() {
@@ -69,3 +71,30 @@ fn bech32_into_inner() {
 
     assert_eq!(address, bech32_address.into_inner());
 }
+
+#[test]
+fn bech32_round_trip() {
+    for bech32 in [ED25519_BECH32, ALIAS_BECH32, NFT_BECH32] {
+        let bech32_address = Bech32Address::from_str(bech32).unwrap();
+        let address = Address::from(bech32_address);
+
+        assert_eq!(address, Address::try_from_bech32(bech32).unwrap());
+        assert_eq!(address.to_bech32(*bech32_address.hrp()), bech32_address);
+        assert_eq!(address.try_to_bech32("rms").unwrap().to_string(), bech32);
+        assert_eq!(Address::from(&bech32_address), address);
+    }
+}
+
+#[test]
+fn bech32_hrp_matches() {
+    let hrp = Hrp::from_str("rms").unwrap();
+
+    assert_eq!(
+        Bech32Address::try_from_str_with_hrp(ED25519_BECH32, hrp).unwrap(),
+        Bech32Address::from_str(ED25519_BECH32).unwrap()
+    );
+    assert!(matches!(
+        Bech32Address::try_from_str_with_hrp(ED25519_BECH32, "smr"),
+        Err(Error::Bech32HrpMismatch { provided, expected }) if provided == "rms" && expected == "smr"
+    ));
+}
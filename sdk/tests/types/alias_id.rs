@@ -0,0 +1,36 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::str::FromStr;
+
+use iota_sdk::types::block::{
+    output::{AliasId, OutputId},
+    payload::transaction::TransactionId,
+};
+use pretty_assertions::assert_eq;
+
+const TRANSACTION_ID: &str = "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649";
+
+// The alias ID is the BLAKE2b-256 hash of the output ID that created the alias.
+#[test]
+fn from_output_id() {
+    let transaction_id = TransactionId::from_str(TRANSACTION_ID).unwrap();
+
+    assert_eq!(
+        AliasId::from(&OutputId::new(transaction_id, 0).unwrap()),
+        AliasId::from_str("0xfe80c2eb7c736da2f7c98ecf135ee9e34e4e076afe6e1dfebc9ec578b8f56d2f").unwrap()
+    );
+    assert_eq!(
+        AliasId::from(&OutputId::new(transaction_id, 42).unwrap()),
+        AliasId::from_str("0xf29dd16310c2100fd1bf568b345fb1cc14d71caa3bd9b5ad735d2bd6d455ca3b").unwrap()
+    );
+}
+
+#[test]
+fn or_from_output_id() {
+    let output_id = OutputId::new(TransactionId::from_str(TRANSACTION_ID).unwrap(), 0).unwrap();
+    let alias_id = AliasId::from_str("0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649").unwrap();
+
+    assert_eq!(AliasId::null().or_from_output_id(&output_id), AliasId::from(&output_id));
+    assert_eq!(alias_id.or_from_output_id(&output_id), alias_id);
+}
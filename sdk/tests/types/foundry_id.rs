@@ -5,7 +5,8 @@ use core::str::FromStr;
 
 use iota_sdk::types::block::{
     address::AliasAddress,
-    output::{AliasId, FoundryId, SimpleTokenScheme, TokenScheme},
+    output::{AliasId, FoundryId, OutputId, SimpleTokenScheme, TokenId, TokenScheme},
+    payload::transaction::TransactionId,
 };
 use pretty_assertions::assert_eq;
 
@@ -26,3 +27,19 @@ fn getters() {
         FoundryId::from_str("0x0852fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c6492a00000000").unwrap()
     );
 }
+
+// The foundry ID is the serialized alias address of the controlling alias, followed by the serial number and the token
+// scheme kind.
+#[test]
+fn from_alias_created_in_output() {
+    let transaction_id =
+        TransactionId::from_str("0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649").unwrap();
+    let alias_id = AliasId::from(&OutputId::new(transaction_id, 0).unwrap());
+    let foundry_id = FoundryId::build(&AliasAddress::from(alias_id), 1, SimpleTokenScheme::KIND);
+
+    assert_eq!(
+        foundry_id,
+        FoundryId::from_str("0x08fe80c2eb7c736da2f7c98ecf135ee9e34e4e076afe6e1dfebc9ec578b8f56d2f0100000000").unwrap()
+    );
+    assert_eq!(TokenId::from(foundry_id).to_string(), foundry_id.to_string());
+}
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod address;
+mod alias_id;
 mod api;
 #[cfg(feature = "pow")]
 mod block;
@@ -14,6 +15,7 @@ mod milestone_id;
 mod milestone_index;
 mod milestone_payload;
 mod milestone_payload_essence;
+mod nft_id;
 mod output_id;
 mod parents;
 mod payload;
@@ -0,0 +1,36 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::str::FromStr;
+
+use iota_sdk::types::block::{
+    output::{NftId, OutputId},
+    payload::transaction::TransactionId,
+};
+use pretty_assertions::assert_eq;
+
+const TRANSACTION_ID: &str = "0x9566c74d10037c4d7bbb0407d1e2c64981855ad8681d0d86d1e91e00167939cb";
+
+// The nft ID is the BLAKE2b-256 hash of the output ID that created the nft.
+#[test]
+fn from_output_id() {
+    let transaction_id = TransactionId::from_str(TRANSACTION_ID).unwrap();
+
+    assert_eq!(
+        NftId::from(&OutputId::new(transaction_id, 1).unwrap()),
+        NftId::from_str("0xeeb9da177add4c46a6cb9359caa06c1f791e3ebcfa079089ee56fa9fc554aed7").unwrap()
+    );
+    assert_eq!(
+        NftId::from(&OutputId::new(transaction_id, 127).unwrap()),
+        NftId::from_str("0x96217799e5f688a93d821d7d9e47f53ca13cc5328e9391f6799ade10a349e731").unwrap()
+    );
+}
+
+#[test]
+fn or_from_output_id() {
+    let output_id = OutputId::new(TransactionId::from_str(TRANSACTION_ID).unwrap(), 1).unwrap();
+    let nft_id = NftId::from_str("0x96217799e5f688a93d821d7d9e47f53ca13cc5328e9391f6799ade10a349e731").unwrap();
+
+    assert_eq!(NftId::null().or_from_output_id(&output_id), NftId::from(&output_id));
+    assert_eq!(nft_id.or_from_output_id(&output_id), nft_id);
+}
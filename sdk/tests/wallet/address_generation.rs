@@ -91,6 +91,66 @@ async fn wallet_address_generation_stronghold() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[cfg(feature = "stronghold")]
+#[tokio::test]
+async fn wallet_with_unlocked_stronghold() -> Result<()> {
+    let storage_path = "test-storage/wallet_with_unlocked_stronghold";
+    setup(storage_path)?;
+
+    iota_stronghold::engine::snapshot::try_set_encrypt_work_factor(0).unwrap();
+
+    let stronghold_password = "some_hopefully_secure_password".to_owned();
+    let secret_manager = StrongholdSecretManager::builder()
+        .password(stronghold_password.clone())
+        .timeout(std::time::Duration::from_millis(10))
+        .build("test-storage/wallet_with_unlocked_stronghold/test.stronghold")?;
+    secret_manager
+        .store_mnemonic(Mnemonic::from(DEFAULT_MNEMONIC.to_string()))
+        .await?;
+
+    let client_options = ClientOptions::new().with_node(NODE_LOCAL)?;
+    #[allow(unused_mut)]
+    let mut wallet_builder = Wallet::builder()
+        .with_secret_manager(SecretManager::Stronghold(secret_manager))
+        .with_client_options(client_options)
+        .with_coin_type(IOTA_COIN_TYPE);
+    #[cfg(feature = "storage")]
+    {
+        wallet_builder = wallet_builder.with_storage_path(storage_path);
+    }
+    let wallet = wallet_builder.finish().await?;
+    wallet.clear_stronghold_password().await?;
+
+    let address = wallet
+        .with_unlocked_stronghold(stronghold_password.clone(), || async {
+            // Wait longer than the clear interval, the key must still be available.
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+            assert!(wallet.is_stronghold_password_available().await?);
+            wallet.generate_ed25519_address(0, 0, None).await
+        })
+        .await?;
+
+    assert_eq!(
+        address.to_bech32_unchecked("smr"),
+        // Address generated with bip32 path: [44, 4218, 0, 0, 0].
+        "smr1qrpwecegav7eh0z363ca69laxej64rrt4e3u0rtycyuh0mam3vq3ulygj9p"
+    );
+    assert!(!wallet.is_stronghold_password_available().await?);
+
+    // Dropping the future before the operations complete must not leave the Stronghold unlocked
+    let unlocked = wallet.with_unlocked_stronghold(stronghold_password, std::future::pending::<Result<()>>);
+    assert!(
+        tokio::time::timeout(std::time::Duration::from_millis(50), unlocked)
+            .await
+            .is_err()
+    );
+    // The key is cleared in a task after the drop
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    assert!(!wallet.is_stronghold_password_available().await?);
+
+    tear_down(storage_path)
+}
+
 #[tokio::test]
 #[cfg(all(feature = "ledger_nano", feature = "events"))]
 #[ignore = "requires ledger nano instance"]
@@ -166,6 +166,97 @@ async fn balance_expiration() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn balance_snapshot() -> Result<()> {
+    let storage_path = "test-storage/balance_snapshot";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let snapshot = account_0.snapshot().await?;
+    assert_eq!(snapshot.balance(), &account_0.balance().await?);
+    assert_eq!(snapshot.details(), &*account_0.details().await);
+
+    // The snapshot doesn't change when the account does
+    account_0
+        .send(1_000_000, account_1.addresses().await?[0].address(), None)
+        .await?;
+    assert_ne!(snapshot.balance(), &account_0.balance().await?);
+    assert_ne!(snapshot.details(), &*account_0.details().await);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn balance_at() -> Result<()> {
+    let storage_path = "test-storage/balance_at";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let balance_before = account_0.sync(None).await?;
+    let time_before = account_0.client().get_time_checked().await?;
+    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+
+    let tx = account_0
+        .send(
+            balance_before.base_coin().available(),
+            account_1.generate_ed25519_addresses(1, None).await?[0].address(),
+            None,
+        )
+        .await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account_0.sync(None).await?;
+
+    let balance_at = account_0.balance_at(time_before).await?;
+    assert_eq!(balance_at.base_coin().total(), balance_before.base_coin().total());
+    let time_after = account_0.client().get_time_checked().await?;
+    assert_eq!(account_0.balance_at(time_after).await?.base_coin().total(), 0);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn balance_stream() -> Result<()> {
+    use futures::StreamExt;
+
+    let storage_path = "test-storage/balance_stream";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    let balances = wallet.balance_stream(None, std::time::Duration::from_secs(1));
+    futures::pin_mut!(balances);
+
+    // The initial balance is yielded after the first sync
+    assert_eq!(balances.next().await.unwrap()?.base_coin().total(), 0);
+
+    iota_sdk::client::request_funds_from_faucet(
+        crate::wallet::common::FAUCET_URL,
+        account.addresses().await?[0].address(),
+    )
+    .await?;
+
+    // The next balance is only yielded once it changed
+    let balance = balances.next().await.unwrap()?;
+    assert!(balance.base_coin().total() > 0);
+    assert_eq!(balance, account.balance().await?);
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn addresses_balance() -> Result<()> {
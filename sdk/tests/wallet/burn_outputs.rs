@@ -5,7 +5,7 @@ use iota_sdk::{
     client::api::input_selection::Burn,
     types::block::output::{
         unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition},
-        NativeToken, NftId, NftOutputBuilder, OutputId, UnlockCondition,
+        FoundryId, NativeToken, NftId, NftOutputBuilder, OutputId, TokenId, UnlockCondition,
     },
     wallet::{Account, CreateNativeTokenParams, MintNftParams, Result},
     U256,
@@ -54,6 +54,50 @@ async fn mint_and_burn_nft() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn mint_and_estimate_nft_burn() -> Result<()> {
+    let storage_path = "test-storage/mint_and_estimate_nft_burn";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+
+    let nft_options = [MintNftParams::new().with_address(*account.addresses().await?[0].address())];
+
+    let transaction = account.mint_nfts(nft_options, None).await.unwrap();
+    account
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+    account.sync(None).await?;
+
+    let output_id = OutputId::new(transaction.transaction_id, 0u16).unwrap();
+    let nft_id = NftId::from(&output_id);
+    let nft_amount = account.get_output(&output_id).await.unwrap().output.amount();
+
+    let estimate = account.estimate_burn(nft_id, None).await?;
+    assert_eq!(estimate.destroyed_outputs, [output_id]);
+    assert_eq!(estimate.released_storage_deposit, nft_amount);
+    assert!(estimate.burned_native_tokens.is_empty());
+
+    // The estimate doesn't keep the inputs locked
+    let burn = account.burn_with_confirmation(nft_id, None).await?;
+    assert!(
+        burn.prepared_transaction_data()
+            .inputs_data
+            .iter()
+            .any(|input| input.output_id() == &output_id)
+    );
+    let transaction = burn.confirm().await?;
+    account
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+    let balance = account.sync(None).await?;
+    assert!(!balance.nfts().contains(&nft_id));
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn mint_and_burn_expired_nft() -> Result<()> {
@@ -158,6 +202,14 @@ async fn create_and_melt_native_token() -> Result<()> {
     });
     assert!(search.is_some());
 
+    // Melting more than the circulating supply fails
+    assert!(matches!(
+        account
+            .prepare_melt_native_token(create_transaction.token_id, circulating_supply, None)
+            .await,
+        Err(iota_sdk::wallet::Error::BurningOrMeltingFailed(_))
+    ));
+
     // Then melt the rest of the supply
     let melt_amount = circulating_supply - melt_amount;
     let transaction = account
@@ -276,6 +328,72 @@ async fn create_and_burn_native_tokens() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn create_and_burn_half_of_native_tokens() -> Result<()> {
+    let storage_path = "test-storage/create_and_burn_half_of_native_tokens";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+
+    let native_token_amount = U256::from(100);
+
+    let tx = account.create_alias_output(None, None).await?;
+    account
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account.sync(None).await?;
+
+    let create_tx = account
+        .create_native_token(
+            CreateNativeTokenParams {
+                alias_id: None,
+                circulating_supply: native_token_amount,
+                maximum_supply: native_token_amount,
+                foundry_metadata: None,
+            },
+            None,
+        )
+        .await?;
+    account
+        .retry_transaction_until_included(&create_tx.transaction.transaction_id, None, None)
+        .await?;
+    account.sync(None).await?;
+
+    let prepared = account
+        .prepare_burn(NativeToken::new(create_tx.token_id, native_token_amount / 2)?, None)
+        .await?;
+    // The un-burned half goes back to the account in the remainder
+    let remainder = prepared.remainder.as_ref().unwrap();
+    assert_eq!(
+        remainder
+            .output
+            .native_tokens()
+            .unwrap()
+            .get(&create_tx.token_id)
+            .unwrap()
+            .amount(),
+        native_token_amount / 2
+    );
+
+    let tx = account.sign_and_submit_transaction(prepared, None).await?;
+    account
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    let balance = account.sync(None).await?;
+
+    let token = balance
+        .native_tokens()
+        .iter()
+        .find(|token| token.token_id() == &create_tx.token_id)
+        .unwrap();
+    assert_eq!(token.total(), native_token_amount / 2);
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn mint_and_burn_nft_with_alias() -> Result<()> {
@@ -317,3 +435,99 @@ async fn mint_and_burn_nft_with_alias() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn burn_alias_controlling_foundry() -> Result<()> {
+    let storage_path = "test-storage/burn_alias_controlling_foundry";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+
+    let tx = account.create_alias_output(None, None).await?;
+    account
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account.sync(None).await?;
+
+    let create_tx = account
+        .create_native_token(
+            CreateNativeTokenParams {
+                alias_id: None,
+                circulating_supply: U256::from(100),
+                maximum_supply: U256::from(100),
+                foundry_metadata: None,
+            },
+            None,
+        )
+        .await?;
+    account
+        .retry_transaction_until_included(&create_tx.transaction.transaction_id, None, None)
+        .await?;
+    let balance = account.sync(None).await?;
+    let alias_id = *balance.aliases().first().unwrap();
+
+    let err = account.prepare_burn(alias_id, None).await.unwrap_err();
+    match err {
+        iota_sdk::wallet::Error::AliasControlsOutputs {
+            alias_id: err_alias_id,
+            foundries,
+            nfts,
+        } => {
+            assert_eq!(err_alias_id, alias_id);
+            assert_eq!(foundries, vec![FoundryId::from(create_tx.token_id)]);
+            assert!(nfts.is_empty());
+        }
+        _ => panic!("unexpected error {err}"),
+    }
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn burn_native_tokens_not_held() -> Result<()> {
+    let storage_path = "test-storage/burn_native_tokens_not_held";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    let token_id = TokenId::null();
+    let err = account
+        .prepare_burn((token_id, U256::from(10)), None)
+        .await
+        .unwrap_err();
+    match err {
+        iota_sdk::wallet::Error::InsufficientNativeTokens {
+            token_id: err_token_id,
+            available,
+            required,
+        } => {
+            assert_eq!(err_token_id, token_id);
+            assert_eq!(available, U256::zero());
+            assert_eq!(required, U256::from(10));
+        }
+        _ => panic!("unexpected error {err}"),
+    }
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn melt_native_token_without_foundry() -> Result<()> {
+    let storage_path = "test-storage/melt_native_token_without_foundry";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    let token_id = TokenId::null();
+    let err = account.prepare_melt_native_token(token_id, 10, None).await.unwrap_err();
+    match err {
+        iota_sdk::wallet::Error::FoundryNotFound(err_token_id) => assert_eq!(err_token_id, token_id),
+        _ => panic!("unexpected error {err}"),
+    }
+
+    tear_down(storage_path)
+}
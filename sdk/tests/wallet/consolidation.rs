@@ -1,7 +1,13 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::{account::ConsolidationParams, Result, SendParams};
+use iota_sdk::{
+    wallet::{
+        account::{AutoConsolidateOptions, ConsolidationParams, NativeTokenConsolidationStrategy, SyncOptions},
+        CreateNativeTokenParams, Result, SendParams,
+    },
+    U256,
+};
 use pretty_assertions::assert_eq;
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
@@ -49,3 +55,160 @@ async fn consolidation() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn consolidation_skipping_native_tokens() -> Result<()> {
+    let storage_path = "test-storage/consolidation_skipping_native_tokens";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+
+    let tx = account.create_alias_output(None, None).await?;
+    account
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account.sync(None).await?;
+
+    let create_tx = account
+        .create_native_token(
+            CreateNativeTokenParams {
+                alias_id: None,
+                circulating_supply: U256::from(100),
+                maximum_supply: U256::from(100),
+                foundry_metadata: None,
+            },
+            None,
+        )
+        .await?;
+    account
+        .retry_transaction_until_included(&create_tx.transaction.transaction_id, None, None)
+        .await?;
+    account.sync(None).await?;
+
+    let outputs_with_native_tokens = account
+        .unspent_outputs(None)
+        .await?
+        .iter()
+        .filter(|output_data| {
+            output_data.output.is_basic() && output_data.output.native_tokens().map_or(false, |nts| !nts.is_empty())
+        })
+        .count();
+    assert!(outputs_with_native_tokens > 0);
+
+    let prepared = account
+        .prepare_consolidate_outputs_with_report(
+            ConsolidationParams::new()
+                .with_force(true)
+                .with_native_token_strategy(NativeTokenConsolidationStrategy::Skip),
+        )
+        .await?;
+
+    assert_eq!(prepared.skipped_outputs, outputs_with_native_tokens);
+    assert!(
+        prepared
+            .prepared_transaction_data
+            .inputs_data
+            .iter()
+            .all(|input| input.output.native_tokens().map_or(true, |nts| nts.is_empty()))
+    );
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn auto_consolidation() -> Result<()> {
+    let storage_path = "test-storage/auto_consolidation";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    // Send 10 outputs to account_1
+    let amount = 1_000_000;
+    let tx = account_0
+        .send_with_params(
+            vec![SendParams::new(amount, *account_1.addresses().await?[0].address())?; 10],
+            None,
+        )
+        .await?;
+
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    let sync_options = SyncOptions {
+        auto_consolidate: Some(AutoConsolidateOptions {
+            output_threshold: Some(10),
+        }),
+        ..Default::default()
+    };
+    account_1.sync(Some(sync_options)).await?;
+
+    // The sync submitted a consolidation transaction
+    let pending_transactions = account_1.pending_transactions().await;
+    assert_eq!(pending_transactions.len(), 1);
+    account_1
+        .retry_transaction_until_included(&pending_transactions[0].transaction_id, None, None)
+        .await?;
+
+    let balance = account_1.sync(None).await.unwrap();
+    // Balance still the same
+    assert_eq!(balance.base_coin().available(), 10 * amount);
+    // Only one unspent output
+    assert_eq!(account_1.unspent_outputs(None).await?.len(), 1);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn split_output() -> Result<()> {
+    let storage_path = "test-storage/split_output";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    // Send a single output to account_1
+    let amount = 10_000_000;
+    let tx = account_0
+        .send_with_params(
+            [SendParams::new(amount, *account_1.addresses().await?[0].address())?],
+            None,
+        )
+        .await?;
+
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    account_1.sync(None).await?;
+    let source = account_1.unspent_outputs(None).await?[0].output_id;
+
+    let split = account_1.split_output(source, 10, amount / 10, None, None).await?;
+    assert_eq!(split.output_ids.len(), 10);
+    account_1
+        .retry_transaction_until_included(&split.transaction.transaction_id, None, None)
+        .await?;
+
+    let balance = account_1.sync(None).await?;
+    // Balance still the same
+    assert_eq!(balance.base_coin().available(), amount);
+    // The source got split into 10 outputs without remainder
+    assert_eq!(account_1.unspent_outputs(None).await?.len(), 10);
+    for output_id in split.output_ids {
+        assert_eq!(
+            account_1.get_output(&output_id).await.unwrap().output.amount(),
+            amount / 10
+        );
+    }
+
+    tear_down(storage_path)
+}
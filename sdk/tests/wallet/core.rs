@@ -184,7 +184,7 @@ async fn update_node_auth() -> Result<()> {
 
     let node_auth = iota_sdk::client::node_manager::node::NodeAuth {
         jwt: Some("jwt".to_string()),
-        basic_auth_name_pwd: None,
+        ..Default::default()
     };
     wallet
         .update_node_auth(Url::parse(NODE_OTHER).unwrap(), Some(node_auth.clone()))
@@ -1,6 +1,7 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use futures::StreamExt;
 use iota_sdk::{
     client::api::PreparedTransactionDataDto,
     types::block::{
@@ -15,12 +16,15 @@ use iota_sdk::{
         account::types::{InclusionState, OutputData, OutputDataDto},
         events::types::{
             AddressData, NewOutputEvent, SpentOutputEvent, TransactionInclusionEvent, TransactionProgressEvent,
-            WalletEvent,
+            WalletEvent, WalletEventType,
         },
+        Result,
     },
 };
 use pretty_assertions::assert_eq;
 
+use crate::wallet::common::{make_wallet, setup, tear_down};
+
 const ED25519_ADDRESS: &str = "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649";
 const TRANSACTION_ID: &str = "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649";
 
@@ -123,3 +127,35 @@ fn wallet_events_serde() {
 
     assert_serde_eq(WalletEvent::TransactionProgress(TransactionProgressEvent::Broadcasting));
 }
+
+#[tokio::test]
+async fn wallet_events_subscribe() -> Result<()> {
+    let storage_path = "test-storage/wallet_events_subscribe";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let all_events = wallet.subscribe([]).await;
+    let progress_events = wallet.subscribe([WalletEventType::TransactionProgress]).await;
+    futures::pin_mut!(all_events, progress_events);
+
+    wallet.emit_test_event(WalletEvent::ConsolidationRequired).await;
+    wallet
+        .emit_test_event(WalletEvent::TransactionProgress(TransactionProgressEvent::Broadcasting))
+        .await;
+
+    assert_eq!(
+        all_events.next().await.unwrap().event,
+        WalletEvent::ConsolidationRequired
+    );
+    assert_eq!(
+        all_events.next().await.unwrap().event,
+        WalletEvent::TransactionProgress(TransactionProgressEvent::Broadcasting)
+    );
+    assert_eq!(
+        progress_events.next().await.unwrap().event,
+        WalletEvent::TransactionProgress(TransactionProgressEvent::Broadcasting)
+    );
+
+    tear_down(storage_path)
+}
@@ -2,12 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use iota_sdk::{
-    types::block::output::{
-        unlock_condition::{
-            AddressUnlockCondition, ExpirationUnlockCondition, GovernorAddressUnlockCondition,
-            StateControllerAddressUnlockCondition, StorageDepositReturnUnlockCondition,
+    types::block::{
+        output::{
+            unlock_condition::{
+                AddressUnlockCondition, ExpirationUnlockCondition, GovernorAddressUnlockCondition,
+                StateControllerAddressUnlockCondition, StorageDepositReturnUnlockCondition,
+            },
+            AliasId, AliasOutputBuilder, BasicOutputBuilder, NftId, NftOutputBuilder, OutputId, UnlockCondition,
         },
-        AliasId, AliasOutputBuilder, BasicOutputBuilder, NftId, NftOutputBuilder, UnlockCondition,
+        payload::transaction::TransactionId,
     },
     wallet::{account::SyncOptions, Result},
 };
@@ -234,3 +237,96 @@ async fn background_syncing() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn sync_amount_range() -> Result<()> {
+    let storage_path = "test-storage/sync_amount_range";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let account_1_address = *account_1.addresses().await?[0].address().as_ref();
+
+    let token_supply = account_0.client().get_token_supply().await?;
+
+    let outputs = [
+        BasicOutputBuilder::new_with_amount(750_000)
+            .with_unlock_conditions([AddressUnlockCondition::new(account_1_address)])
+            .finish_output(token_supply)?,
+        BasicOutputBuilder::new_with_amount(250_000)
+            .with_unlock_conditions([AddressUnlockCondition::new(account_1_address)])
+            .finish_output(token_supply)?,
+    ];
+
+    let tx = account_0.send_outputs(outputs, None).await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    // Only the bigger output is in the range
+    let balance = account_1
+        .sync(Some(SyncOptions {
+            amount_range: Some(500_000..=u64::MAX),
+            ..Default::default()
+        }))
+        .await?;
+    assert_eq!(balance.base_coin().total(), 750_000);
+    assert_eq!(account_1.unspent_outputs(None).await?.len(), 1);
+
+    // Without a range both outputs are considered again
+    let balance = account_1
+        .sync(Some(SyncOptions {
+            force_syncing: true,
+            ..Default::default()
+        }))
+        .await?;
+    assert_eq!(balance.base_coin().total(), 1_000_000);
+    assert_eq!(account_1.unspent_outputs(None).await?.len(), 2);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn get_output_with_metadata() -> Result<()> {
+    let storage_path = "test-storage/get_output_with_metadata";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+
+    // Outputs known to the account are returned from its state
+    let output_data = account.unspent_outputs(None).await?.remove(0);
+    let output = account
+        .get_output_with_metadata(&output_data.output_id, false)
+        .await?
+        .unwrap();
+    assert_eq!(output.output(), &output_data.output);
+    assert_eq!(output.metadata(), &output_data.metadata);
+    assert_eq!(
+        account.get_output_metadata(&output_data.output_id, false).await?,
+        Some(output_data.metadata)
+    );
+
+    // Unknown outputs are only requested from the node if allowed
+    let unknown_output_id = OutputId::new(TransactionId::new([1; 32]), 0)?;
+    assert!(
+        account
+            .get_output_with_metadata(&unknown_output_id, false)
+            .await?
+            .is_none()
+    );
+    assert!(
+        account
+            .get_output_with_metadata(&unknown_output_id, true)
+            .await?
+            .is_none()
+    );
+    assert!(account.get_output_metadata(&unknown_output_id, true).await?.is_none());
+
+    tear_down(storage_path)
+}
@@ -1,7 +1,14 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::{account::TransactionOptions, MintNftParams, Result, SendNftParams, SendParams};
+use iota_sdk::{
+    client::clock::MockClock,
+    types::block::{
+        output::OutputId,
+        payload::transaction::{TransactionEssence, TransactionId},
+    },
+    wallet::{account::TransactionOptions, Error, MintNftParams, Result, SendNftParams, SendParams},
+};
 use pretty_assertions::assert_eq;
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
@@ -35,6 +42,106 @@ async fn send_amount() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn send_max() -> Result<()> {
+    let storage_path = "test-storage/send_max";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let available = account_0.balance().await?.base_coin().available();
+    let prepared = account_0
+        .prepare_send_max(*account_1.addresses().await?[0].address(), None)
+        .await?;
+    // Without native tokens, nothing needs to stay in the account
+    assert_eq!(prepared.amount, available);
+
+    let tx = account_0
+        .sign_and_submit_transaction(prepared.prepared_transaction_data, None)
+        .await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    assert_eq!(account_0.sync(None).await?.base_coin().available(), 0);
+    assert_eq!(account_1.sync(None).await?.base_coin().available(), available);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn retry_transaction_with_report() -> Result<()> {
+    let storage_path = "test-storage/retry_transaction_with_report";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let amount = 1_000_000;
+    let tx = account_0
+        .send_with_params(
+            [SendParams::new(amount, *account_1.addresses().await?[0].address())?],
+            None,
+        )
+        .await?;
+
+    let report = account_0
+        .retry_transaction_until_included_with_report(&tx.transaction_id, None, None, None)
+        .await?;
+
+    assert!(report.included_block_id.is_some());
+    assert_eq!(report.attempts[0].block_id, tx.block_id.unwrap());
+    assert!(report.attempts.iter().all(|attempt| !attempt.parents.is_empty()));
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn wait_for_transactions_inclusion() -> Result<()> {
+    let storage_path = "test-storage/wait_for_transactions_inclusion";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let accounts = create_accounts_with_funds(&wallet, 2).await?;
+    let receiver = wallet.create_account().finish().await?;
+    let address = *receiver.addresses().await?[0].address();
+
+    let mut transaction_ids = Vec::new();
+    for account in &accounts {
+        let tx = account
+            .send_with_params([SendParams::new(1_000_000, address)?], None)
+            .await?;
+        transaction_ids.push(tx.transaction_id);
+    }
+    // Never sent, so it can't get included
+    let unknown_transaction_id = TransactionId::new([1; 32]);
+    transaction_ids.push(unknown_transaction_id);
+
+    // The transactions of other accounts are awaited too
+    let results = accounts[0]
+        .wait_for_transactions_inclusion(&transaction_ids, None, Some(15), false)
+        .await?;
+    assert_eq!(results.len(), 3);
+    for transaction_id in &transaction_ids[..2] {
+        assert!(results[transaction_id].is_ok());
+    }
+    assert!(matches!(
+        results[&unknown_transaction_id],
+        Err(Error::Client(ref error)) if matches!(**error, iota_sdk::client::Error::TangleInclusion(_))
+    ));
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn send_amount_127_outputs() -> Result<()> {
@@ -158,6 +265,66 @@ async fn send_nft() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn send_nft_with_expiration() -> Result<()> {
+    let storage_path = "test-storage/send_nft_with_expiration";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let accounts = &create_accounts_with_funds(&wallet, 2).await?;
+
+    let transaction = accounts[0].mint_nfts([MintNftParams::new()], None).await?;
+    accounts[0]
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+    let nft_id = *accounts[0].sync(None).await?.nfts().first().unwrap();
+    let return_address = *accounts[0].addresses().await?[0].address();
+
+    // Account 1 isn't allowed as return address
+    let result = accounts[0]
+        .prepare_send_nft(
+            [
+                SendNftParams::new(*accounts[1].addresses().await?[0].address(), nft_id)?
+                    .with_return_address(*accounts[1].addresses().await?[0].address())
+                    .with_expiration(86400),
+            ],
+            None,
+        )
+        .await;
+    assert!(matches!(result, Err(Error::AddressNotFoundInAccount(_))));
+
+    let transaction = accounts[0]
+        .send_nft(
+            [
+                SendNftParams::new(*accounts[1].addresses().await?[0].address(), nft_id)?
+                    .with_return_address(return_address)
+                    .with_expiration(86400),
+            ],
+            None,
+        )
+        .await?;
+
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    let nft_output = essence
+        .outputs()
+        .iter()
+        .find(|output| output.is_nft() && output.as_nft().nft_id() == &nft_id)
+        .unwrap()
+        .as_nft();
+    let unlock_conditions = nft_output.unlock_conditions();
+    assert_eq!(
+        unlock_conditions.storage_deposit_return().unwrap().amount(),
+        nft_output.amount()
+    );
+    assert_eq!(
+        unlock_conditions.expiration().unwrap().return_address(),
+        return_address.inner()
+    );
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn send_with_note() -> Result<()> {
@@ -185,6 +352,207 @@ async fn send_with_note() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn send_with_timelock() -> Result<()> {
+    let storage_path = "test-storage/send_with_timelock";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+    let address = *account_1.addresses().await?[0].address();
+    let local_time = account_0.client().get_time_checked().await?;
+
+    // A timelock in the past is rejected
+    let params = SendParams::new(1_000_000, address)?.with_timelock(local_time - 1);
+    assert!(matches!(
+        account_0.prepare_send([params], None).await,
+        Err(Error::InvalidTimelock { expiration: None, .. })
+    ));
+
+    // An output needing a storage deposit return expires after 100 seconds, so the receiver could never claim it
+    let params = SendParams::new(1, address)?
+        .with_expiration(100)
+        .with_timelock(local_time + 200);
+    assert!(matches!(
+        account_0.prepare_send([params], None).await,
+        Err(Error::InvalidTimelock {
+            expiration: Some(_),
+            ..
+        })
+    ));
+
+    let timelock = local_time + 3600;
+    let params = SendParams::new(1_000_000, address)?.with_timelock(timelock);
+    let prepared = account_0.prepare_send([params], None).await?;
+    let TransactionEssence::Regular(essence) = &prepared.essence;
+    assert!(essence.outputs().iter().any(|output| {
+        output
+            .unlock_conditions()
+            .and_then(|unlock_conditions| unlock_conditions.timelock())
+            .map(|timelock| timelock.timestamp())
+            == Some(timelock)
+    }));
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn send_with_sender() -> Result<()> {
+    let storage_path = "test-storage/send_with_sender";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+    let address = *account_1.addresses().await?[0].address();
+
+    // The sender feature can only be set to an address of the sending account
+    let params = SendParams::new(1_000_000, address)?.with_sender(address);
+    assert!(matches!(
+        account_0.prepare_send([params], None).await,
+        Err(Error::AddressNotFoundInAccount(sender)) if sender == address
+    ));
+
+    let sender = *account_0.addresses().await?[0].address();
+    let params = SendParams::new(1_000_000, address)?.with_sender(sender);
+    let prepared = account_0.prepare_send([params], None).await?;
+    let TransactionEssence::Regular(essence) = &prepared.essence;
+    assert!(essence.outputs().iter().any(|output| {
+        output
+            .features()
+            .and_then(|features| features.sender())
+            .map(|feature| *feature.address())
+            == Some(sender.into_inner())
+    }));
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn send_with_mock_clock() -> Result<()> {
+    let storage_path = "test-storage/send_with_mock_clock";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let now = account_0.client().unix_timestamp_now();
+    account_0.client().set_clock(MockClock::new(now));
+
+    let tx = account_0
+        .send(1_000_000, account_1.addresses().await?[0].address(), None)
+        .await?;
+
+    // The transaction time comes from the client clock
+    assert_eq!(tx.timestamp, now.as_millis());
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn send_without_additional_input_selection() -> Result<()> {
+    let storage_path = "test-storage/send_without_additional_input_selection";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    // Create an output which alone can't cover the next transaction
+    let tx = account_0
+        .send_with_params(
+            [SendParams::new(1_000_000, *account_0.addresses().await?[0].address())?],
+            None,
+        )
+        .await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account_0.sync(None).await?;
+
+    let result = account_0
+        .send_with_params(
+            [SendParams::new(1_500_000, *account_1.addresses().await?[0].address())?],
+            Some(TransactionOptions {
+                mandatory_inputs: Some(vec![OutputId::new(tx.transaction_id, 0)?]),
+                allow_additional_input_selection: false,
+                ..Default::default()
+            }),
+        )
+        .await;
+
+    assert!(matches!(result, Err(Error::InsufficientFunds { .. })));
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn send_with_frozen_outputs() -> Result<()> {
+    let storage_path = "test-storage/send_with_frozen_outputs";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let output_ids = account_0
+        .unspent_outputs(None)
+        .await?
+        .into_iter()
+        .map(|output| output.output_id)
+        .collect::<Vec<_>>();
+    for output_id in &output_ids {
+        account_0.freeze_output(*output_id).await?;
+    }
+    assert_eq!(account_0.frozen_outputs().await.len(), output_ids.len());
+    // Frozen outputs are still part of the total balance, but not available
+    let balance = account_0.balance().await?;
+    assert!(balance.base_coin().total() > 0);
+    assert_eq!(balance.base_coin().available(), 0);
+    // Only unspent outputs of the account can be frozen
+    let unknown_output_id = OutputId::new(TransactionId::new([0; 32]), 0)?;
+    assert!(matches!(
+        account_0.freeze_output(unknown_output_id).await,
+        Err(Error::OutputNotFoundInUnspentOutputs(output_id)) if output_id == unknown_output_id
+    ));
+
+    let params = [SendParams::new(1_000_000, *account_1.addresses().await?[0].address())?];
+    assert!(account_0.send_with_params(params.clone(), None).await.is_err());
+    let result = account_0
+        .send_with_params(
+            params.clone(),
+            Some(TransactionOptions {
+                custom_inputs: Some(vec![output_ids[0]]),
+                ..Default::default()
+            }),
+        )
+        .await;
+    assert!(matches!(result, Err(Error::CustomInput(_))));
+
+    account_0.unfreeze_output(&output_ids[0]).await?;
+    let tx = account_0.send_with_params(params, None).await?;
+    // Only the unfrozen output can be used
+    assert!(
+        tx.inputs
+            .iter()
+            .all(|input| *input.metadata.output_id() == output_ids[0])
+    );
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn conflicting_transaction() -> Result<()> {